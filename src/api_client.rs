@@ -1,15 +1,31 @@
+mod blog;
+mod blog_search;
 mod bundle;
 mod comment;
+mod dialog;
+mod donation;
+mod draft;
+mod feed;
+mod moderation;
+mod notification;
+mod poll;
 mod post;
+mod post_settings;
+mod promo;
+mod reaction;
 mod showcase;
+mod stats;
+mod subscriber;
 mod subscription_level;
 mod target;
+mod upload;
 mod user;
 
 use crate::auth_provider::AuthProvider;
 use crate::error::{ApiError, ResultApi, ResultAuth};
 use reqwest::header::{ACCEPT, CACHE_CONTROL, HeaderMap, HeaderValue, USER_AGENT};
 use reqwest::{Client, Response, multipart};
+use std::future::Future;
 
 /// Default number of posts to fetch per page.
 const DEFAULT_PAGE_SIZE: usize = 20;
@@ -248,6 +264,32 @@ impl ApiClient {
         request.send().await.map_err(ApiError::HttpRequest)
     }
 
+    /// Internal: perform a PUT request with multipart form.
+    ///
+    /// Automatically applies authentication headers and prepends the base URL (`/v1/` prefix).
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: relative API path under `/v1/`.
+    /// - `form`: a multipart form.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `reqwest::Response`.  
+    /// On network failure, returns [`ApiError::HttpRequest`].
+    async fn put_multipart(&self, path: &str, form: multipart::Form) -> ResultApi<Response> {
+        let mut headers = self.headers.clone();
+        self.auth_provider.apply_auth_header(&mut headers).await?;
+
+        headers.remove("Content-Type");
+
+        let url = format!("{}/v1/{}", self.base_url, path);
+
+        let request = self.client.put(&url).headers(headers).multipart(form);
+
+        request.send().await.map_err(ApiError::HttpRequest)
+    }
+
     /// Internal: perform a DELETE request to the given API path.
     ///
     /// Automatically applies authentication headers and prepends the base URL (`/v1/` prefix).
@@ -310,3 +352,42 @@ impl ApiClient {
         request.send().await.map_err(ApiError::HttpRequest)
     }
 }
+
+/// Collect every item of an offset-paged listing.
+///
+/// `fetch` is called with the offset to request (`None` for the first page) and returns
+/// the page items together with the listing `total`. The next offset is the requested
+/// offset plus the page length, so paging always moves forward even if the server
+/// echoes a stale offset. Paging stops on an empty page or once `total` is reached.
+///
+/// # Errors
+///
+/// - Any error returned by `fetch`.
+/// - `ApiError::Other` if the next offset does not fit in `u32`.
+pub(crate) async fn paginate_by_offset<T, F, Fut>(mut fetch: F) -> ResultApi<Vec<T>>
+where
+    F: FnMut(Option<u32>) -> Fut,
+    Fut: Future<Output = ResultApi<(Vec<T>, u64)>>,
+{
+    let mut items = Vec::new();
+    let mut offset = None;
+
+    loop {
+        let (data, total) = fetch(offset).await?;
+        let page_len = data.len() as u64;
+        let next = u64::from(offset.unwrap_or(0))
+            .checked_add(page_len)
+            .ok_or_else(|| ApiError::Other("page offset overflow".into()))?;
+        items.extend(data);
+
+        if page_len == 0 || next >= total {
+            break;
+        }
+        offset = Some(
+            u32::try_from(next)
+                .map_err(|_| ApiError::Other(format!("page offset {next} does not fit in u32")))?,
+        );
+    }
+
+    Ok(items)
+}
@@ -0,0 +1,53 @@
+use crate::api_client::ApiClient;
+use crate::error::ResultApi;
+use crate::model::{Blog, CurrentUser};
+
+impl ApiClient {
+    /// Fetch the profile of the authenticated user.
+    ///
+    /// Useful to check that the configured credentials are valid before doing other work.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the [`CurrentUser`] profile.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `CurrentUser`.
+    pub async fn get_current_user(&self) -> ResultApi<CurrentUser> {
+        let path = "user/current";
+
+        let response = self.get_request(path).await?;
+        let response = self.handle_response(path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Fetch blog metadata: title, cover, flags, counters and the current user's level.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the [`Blog`] info.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses (e.g. 404 for an unknown blog).
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Blog`.
+    pub async fn get_blog(&self, blog_name: &str) -> ResultApi<Blog> {
+        let path = format!("blog/{blog_name}");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
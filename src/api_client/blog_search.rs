@@ -0,0 +1,143 @@
+use crate::api_client::{ApiClient, paginate_by_offset};
+use crate::error::{ApiError, ResultApi};
+use crate::model::{BlogCard, BlogCardsResponse, BlogCategoriesResponse};
+
+impl ApiClient {
+    /// Search blogs by text query.
+    ///
+    /// # Parameters
+    ///
+    /// - `query`: text to search for in blog titles, URLs and descriptions.
+    /// - `limit`: maximum number of blogs (optional).
+    /// - `offset`: number of blogs to skip (optional).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a page of [`BlogCard`]s with the total number of matches.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `query` is blank.
+    /// - `ApiError::Serialization` if the query string cannot be encoded.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `BlogCardsResponse`.
+    pub async fn search_blogs(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> ResultApi<BlogCardsResponse> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(ApiError::InvalidInput("search query is empty".into()));
+        }
+
+        let params = serde_urlencoded::to_string([
+            ("query", Some(query.to_string())),
+            ("limit", limit.map(|l| l.to_string())),
+            ("offset", offset.map(|o| o.to_string())),
+        ])?;
+        let path = format!("search/blog/?{params}");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get categories available for blog discovery.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `BlogCategoriesResponse`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `BlogCategoriesResponse`.
+    pub async fn get_blog_categories(&self) -> ResultApi<BlogCategoriesResponse> {
+        let path = "blog/categories/";
+
+        let response = self.get_request(path).await?;
+        let response = self.handle_response(path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// List blogs for discovery, optionally restricted to one category.
+    ///
+    /// # Parameters
+    ///
+    /// - `category_id`: category from [`ApiClient::get_blog_categories`]; `None` lists all blogs.
+    /// - `limit`: maximum number of blogs (optional).
+    /// - `offset`: number of blogs to skip (optional).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a page of [`BlogCard`]s with the total number of blogs.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `BlogCardsResponse`.
+    pub async fn discover_blogs(
+        &self,
+        category_id: Option<u64>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> ResultApi<BlogCardsResponse> {
+        let mut path = "blog/discover/".to_string();
+        let mut params = Vec::new();
+        if let Some(c) = category_id {
+            params.push(format!("category_id={c}"));
+        }
+        if let Some(l) = limit {
+            params.push(format!("limit={l}"));
+        }
+        if let Some(o) = offset {
+            params.push(format!("offset={o}"));
+        }
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// List all blogs for discovery, following offsets until `total` is reached.
+    ///
+    /// # Parameters
+    ///
+    /// - `category_id`: category to list; `None` lists all blogs.
+    /// - `page_size`: blogs per request (optional).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns all [`BlogCard`]s.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by [`ApiClient::discover_blogs`].
+    /// - `ApiError::Other` if the next offset does not fit in `u32`.
+    pub async fn discover_all_blogs(
+        &self,
+        category_id: Option<u64>,
+        page_size: Option<u32>,
+    ) -> ResultApi<Vec<BlogCard>> {
+        paginate_by_offset(|offset| async move {
+            let page = self.discover_blogs(category_id, page_size, offset).await?;
+            Ok((page.data, page.total))
+        })
+        .await
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     api_client::ApiClient,
     error::{ApiError, ResultApi},
-    model::{BundleItemsResponse, BundleQuery, BundlesResponse},
+    model::{Bundle, BundleItem, BundleItemsResponse, BundleQuery, BundlesResponse, NewBundle},
 };
 
 impl ApiClient {
@@ -49,7 +49,35 @@ impl ApiClient {
         bundle_id: &str,
         query: &BundleQuery,
     ) -> ResultApi<BundleItemsResponse> {
-        let query_string = serde_urlencoded::to_string(query).map_err(ApiError::Serialization)?;
+        self.get_bundle_page(blog_name, bundle_id, query, None)
+            .await
+    }
+
+    /// Get a page of posts within a bundle, starting at `offset`.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle_id` - Bundle UUID
+    /// * `query` - Bundle query
+    /// * `offset` - Offset of the page to fetch, as returned in `BundleExtra.offset` (optional)
+    ///
+    /// # Returns
+    /// * On success, returns a `BundleItemsResponse` containing the `bundleItems` field with `BundleItem` items.
+    ///
+    /// # Errors
+    /// * Same as `get_bundle`.
+    pub async fn get_bundle_page(
+        &self,
+        blog_name: &str,
+        bundle_id: &str,
+        query: &BundleQuery,
+        offset: Option<usize>,
+    ) -> ResultApi<BundleItemsResponse> {
+        let mut query_string =
+            serde_urlencoded::to_string(query).map_err(ApiError::Serialization)?;
+        if let Some(offset) = offset {
+            query_string.push_str(&format!("&offset={offset}"));
+        }
 
         let path = format!("blog/{blog_name}/bundle/{bundle_id}/post/?{query_string}");
 
@@ -58,4 +86,213 @@ impl ApiClient {
 
         self.parse_json(response).await
     }
+
+    /// Get all posts within a bundle, following `BundleExtra.offset` until the last page.
+    ///
+    /// `query` applies to every page.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle_id` - Bundle UUID
+    /// * `query` - Bundle query
+    ///
+    /// # Returns
+    /// * On success, returns all `BundleItem` items in page order.
+    ///
+    /// # Errors
+    /// * Any error returned by `get_bundle_page`.
+    pub async fn get_all_bundle_items(
+        &self,
+        blog_name: &str,
+        bundle_id: &str,
+        query: &BundleQuery,
+    ) -> ResultApi<Vec<BundleItem>> {
+        let mut offset = None;
+        let mut items = Vec::new();
+
+        loop {
+            let page = self
+                .get_bundle_page(blog_name, bundle_id, query, offset)
+                .await?;
+            let page_len = page.data.bundle_items.len();
+            items.extend(page.data.bundle_items);
+
+            if page.extra.is_last || page_len == 0 || offset == Some(page.extra.offset) {
+                break;
+            }
+            offset = Some(page.extra.offset);
+        }
+
+        Ok(items)
+    }
+
+    /// Create a new bundle.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle` - Title, description, price, visibility and sorting of the bundle
+    ///
+    /// # Returns
+    /// * On success, returns the created `Bundle`.
+    ///
+    /// # Errors
+    /// * `ApiError::InvalidInput` if `bundle` fails `NewBundle::validate`.
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    /// * `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Bundle`.
+    pub async fn create_bundle(&self, blog_name: &str, bundle: &NewBundle) -> ResultApi<Bundle> {
+        bundle.validate()?;
+
+        let path = format!("blog/{blog_name}/bundle/");
+
+        let response = self.post_request(&path, bundle, true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Update an existing bundle.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle_id` - Bundle UUID
+    /// * `bundle` - New title, description, price, visibility and sorting
+    ///
+    /// # Returns
+    /// * On success, returns the updated `Bundle`.
+    ///
+    /// # Errors
+    /// * `ApiError::InvalidInput` if `bundle` fails `NewBundle::validate`.
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    /// * `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Bundle`.
+    pub async fn update_bundle(
+        &self,
+        blog_name: &str,
+        bundle_id: &str,
+        bundle: &NewBundle,
+    ) -> ResultApi<Bundle> {
+        bundle.validate()?;
+
+        let path = format!("blog/{blog_name}/bundle/{bundle_id}");
+
+        let response = self.put_request(&path, bundle, true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Delete a bundle. Posts in the bundle are not deleted.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle_id` - Bundle UUID
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn delete_bundle(&self, blog_name: &str, bundle_id: &str) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/bundle/{bundle_id}");
+
+        let response = self.delete_request(&path).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Add a post to a bundle.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle_id` - Bundle UUID
+    /// * `post_id` - Post id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn add_bundle_post(
+        &self,
+        blog_name: &str,
+        bundle_id: &str,
+        post_id: &str,
+    ) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/bundle/{bundle_id}/post/");
+
+        let response = self
+            .post_request(&path, &serde_json::json!({ "post_id": post_id }), true)
+            .await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Remove a post from a bundle.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle_id` - Bundle UUID
+    /// * `post_id` - Post id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn remove_bundle_post(
+        &self,
+        blog_name: &str,
+        bundle_id: &str,
+        post_id: &str,
+    ) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/bundle/{bundle_id}/post/{post_id}");
+
+        let response = self.delete_request(&path).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Move a post to a new position within a bundle (see `BundleItem.position`).
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `bundle_id` - Bundle UUID
+    /// * `post_id` - Post id
+    /// * `position` - New position of the post
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn set_bundle_item_position(
+        &self,
+        blog_name: &str,
+        bundle_id: &str,
+        post_id: &str,
+        position: u32,
+    ) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/bundle/{bundle_id}/post/{post_id}");
+
+        let response = self
+            .put_request(&path, &serde_json::json!({ "position": position }), true)
+            .await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@ use reqwest::multipart::{Form, Part};
 
 use crate::{
     api_client::ApiClient,
+    comment_tree::CommentTree,
     error::{ApiError, ResultApi},
     model::{Comment, CommentBlock, CommentsResponse},
 };
@@ -122,6 +123,146 @@ impl ApiClient {
         Ok(all_comments)
     }
 
+    /// Get a page of replies to a comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `parent_id` - Int id of the root comment whose replies are requested
+    /// * `limit` - Limit replies per request (optional)
+    /// * `offset` - Offset (intId of the last received reply) (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `CommentsResponse` containing the `data` field with reply `Comment` items.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `CommentsResponse`.
+    pub async fn get_comment_replies(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        parent_id: u64,
+        limit: Option<u32>,
+        offset: Option<u64>,
+    ) -> ResultApi<CommentsResponse> {
+        let mut path = format!("blog/{blog_name}/post/{post_id}/comment/?parent_id={parent_id}");
+
+        if let Some(o) = offset {
+            path.push_str(&format!("&offset={o}"));
+        }
+        if let Some(l) = limit {
+            path.push_str(&format!("&limit={l}"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get all replies to a comment, paging through the replies endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `parent_id` - Int id of the root comment whose replies are requested
+    /// * `limit` - Limit replies per request (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a vector of reply `Comment` items.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `CommentsResponse`.
+    pub async fn get_all_comment_replies(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        parent_id: u64,
+        limit: Option<u32>,
+    ) -> ResultApi<Vec<Comment>> {
+        let mut all_replies = Vec::new();
+        let mut offset: Option<u64> = None;
+
+        loop {
+            let resp = self
+                .get_comment_replies(blog_name, post_id, parent_id, limit, offset)
+                .await?;
+
+            let last_id = resp.data.last().map(|c| c.int_id);
+            all_replies.extend(resp.data);
+
+            match last_id {
+                Some(id) if !resp.extra.is_last => offset = Some(id),
+                _ => break,
+            }
+        }
+
+        Ok(all_replies)
+    }
+
+    /// Get all comments for a post assembled into a [`CommentTree`].
+    ///
+    /// Root comments are fetched with [`ApiClient::get_all_comments`]. For every root whose
+    /// embedded `replies` page holds fewer comments than `reply_count`, the remaining replies
+    /// are fetched with [`ApiClient::get_all_comment_replies`].
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `order` - Top or bottom (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `CommentTree` with all comments and replies linked.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if a response body cannot be parsed into a `CommentsResponse`.
+    pub async fn get_comment_tree(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        order: Option<&str>,
+    ) -> ResultApi<CommentTree> {
+        let mut comments = self
+            .get_all_comments(blog_name, post_id, None, None, order)
+            .await?;
+
+        let incomplete: Vec<u64> = comments
+            .iter()
+            .filter(|root| {
+                let embedded = root.replies.as_ref().map_or(0, |r| r.data.len());
+                root.reply_count as usize > embedded
+            })
+            .map(|root| root.int_id)
+            .collect();
+
+        for root_id in incomplete {
+            let replies = self
+                .get_all_comment_replies(blog_name, post_id, root_id, None)
+                .await?;
+            comments.extend(replies);
+        }
+
+        Ok(CommentTree::from_comments(comments))
+    }
+
     /// Create a new comment.
     ///
     /// # Arguments
@@ -151,20 +292,7 @@ impl ApiClient {
     ) -> ResultApi<Comment> {
         let path = format!("blog/{blog_name}/post/{post_id}/comment/");
 
-        let mut form = Form::new().text("from_page", "blog");
-
-        for block in blocks {
-            form = form.part(
-                "data[]",
-                Part::text(serde_json::to_string(block).map_err(|e| {
-                    ApiError::JsonParseDetailed {
-                        error: e.to_string(),
-                    }
-                })?)
-                .mime_str("application/json")
-                .map_err(|e| ApiError::Other(e.to_string()))?,
-            );
-        }
+        let mut form = append_blocks(Form::new().text("from_page", "blog"), blocks)?;
 
         if let Some(id) = reply_id {
             form = form.text("reply_id", id.to_string());
@@ -175,4 +303,131 @@ impl ApiClient {
 
         self.parse_json(response).await
     }
+
+    /// Edit an existing comment, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `comment_id` - Comment int id
+    /// * `blocks` - Vector of `CommentBlock` items with the new comment content
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the updated `Comment` (with `is_updated` set).
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Comment`.
+    /// - `ApiError::Other` if form creation fails.
+    pub async fn update_comment(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        comment_id: u64,
+        blocks: &[CommentBlock],
+    ) -> ResultApi<Comment> {
+        let path = format!("blog/{blog_name}/post/{post_id}/comment/{comment_id}");
+
+        let form = append_blocks(Form::new().text("from_page", "blog"), blocks)?;
+
+        let response = self.put_multipart(&path, form).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Delete a comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `comment_id` - Comment int id
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the deleted `Comment` (with `is_deleted` set).
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Comment`.
+    pub async fn delete_comment(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        comment_id: u64,
+    ) -> ResultApi<Comment> {
+        let path = format!("blog/{blog_name}/post/{post_id}/comment/{comment_id}");
+
+        let response = self.delete_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Restore a previously deleted comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `comment_id` - Comment int id
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the restored `Comment` (with `is_deleted` cleared).
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Comment`.
+    pub async fn restore_comment(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        comment_id: u64,
+    ) -> ResultApi<Comment> {
+        let path = format!("blog/{blog_name}/post/{post_id}/comment/{comment_id}/restore/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
+
+/// Append content blocks to a multipart form as JSON `data[]` parts.
+///
+/// This is the block encoding Boosty expects for comments and other
+/// user-authored content.
+///
+/// # Errors
+///
+/// - `ApiError::JsonParseDetailed` if a block cannot be serialized.
+/// - `ApiError::Other` if a part cannot be created.
+pub(crate) fn append_blocks(mut form: Form, blocks: &[CommentBlock]) -> ResultApi<Form> {
+    for block in blocks {
+        form = form.part(
+            "data[]",
+            Part::text(
+                serde_json::to_string(block).map_err(|e| ApiError::JsonParseDetailed {
+                    error: e.to_string(),
+                })?,
+            )
+            .mime_str("application/json")
+            .map_err(|e| ApiError::Other(e.to_string()))?,
+        );
+    }
+
+    Ok(form)
 }
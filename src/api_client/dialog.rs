@@ -0,0 +1,197 @@
+use reqwest::multipart::Form;
+
+use crate::{
+    api_client::{ApiClient, comment::append_blocks},
+    error::{ApiError, ResultApi},
+    model::{CommentBlock, DialogsResponse, Message, MessagesResponse},
+};
+
+impl ApiClient {
+    /// Get a page of the current user's dialogs, with unread counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of dialogs (optional)
+    /// * `offset` - Offset from `DialogsExtra.offset` of the previous page (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `DialogsResponse`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `DialogsResponse`.
+    pub async fn get_dialogs(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u64>,
+    ) -> ResultApi<DialogsResponse> {
+        let mut path = "dialog/".to_string();
+        let mut params = Vec::new();
+        if let Some(l) = limit {
+            params.push(format!("limit={l}"));
+        }
+        if let Some(o) = offset {
+            params.push(format!("offset={o}"));
+        }
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get a page of messages in a dialog, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialog_id` - Dialog id
+    /// * `limit` - Maximum number of messages (optional)
+    /// * `before_id` - Only return messages older than this message id (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `MessagesResponse`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `MessagesResponse`.
+    pub async fn get_dialog_messages(
+        &self,
+        dialog_id: u64,
+        limit: Option<u32>,
+        before_id: Option<u64>,
+    ) -> ResultApi<MessagesResponse> {
+        let mut path = format!("dialog/{dialog_id}/message/");
+        let mut params = Vec::new();
+        if let Some(l) = limit {
+            params.push(format!("limit={l}"));
+        }
+        if let Some(b) = before_id {
+            params.push(format!("before_id={b}"));
+        }
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get all messages in a dialog, newest first, paging back until the oldest message.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialog_id` - Dialog id
+    /// * `page_size` - Messages per request (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns all messages of the dialog.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by `get_dialog_messages`.
+    pub async fn get_all_dialog_messages(
+        &self,
+        dialog_id: u64,
+        page_size: Option<u32>,
+    ) -> ResultApi<Vec<Message>> {
+        let mut messages: Vec<Message> = Vec::new();
+        let mut before_id = None;
+
+        loop {
+            let page = self
+                .get_dialog_messages(dialog_id, page_size, before_id)
+                .await?;
+            let is_last = page.extra.is_last;
+            messages.extend(page.data);
+
+            let oldest = messages.last().map(|m| m.id);
+            if is_last || oldest.is_none() || oldest == before_id {
+                break;
+            }
+            before_id = oldest;
+        }
+
+        Ok(messages)
+    }
+
+    /// Send a message to a dialog.
+    ///
+    /// Content is encoded like comment content. To attach an image, upload it with
+    /// [`ApiClient::upload_image`] and pass the resulting `CommentBlock`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialog_id` - Dialog id
+    /// * `blocks` - Message content blocks
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the sent `Message`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `blocks` is empty.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Message`.
+    /// - `ApiError::Other` if form creation fails.
+    pub async fn send_message(
+        &self,
+        dialog_id: u64,
+        blocks: &[CommentBlock],
+    ) -> ResultApi<Message> {
+        if blocks.is_empty() {
+            return Err(ApiError::InvalidInput("message is empty".into()));
+        }
+
+        let path = format!("dialog/{dialog_id}/message/");
+
+        let form = append_blocks(Form::new(), blocks)?;
+
+        let response = self.post_multipart(&path, form).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Mark all messages in a dialog as read.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialog_id` - Dialog id
+    ///
+    /// # Returns
+    ///
+    /// On success, returns `()`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn mark_dialog_read(&self, dialog_id: u64) -> ResultApi<()> {
+        let path = format!("dialog/{dialog_id}/read/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+}
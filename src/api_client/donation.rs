@@ -0,0 +1,48 @@
+use crate::api_client::ApiClient;
+use crate::error::ResultApi;
+use crate::model::{BlogFlags, DonationOutcome, DonationRequest};
+
+impl ApiClient {
+    /// Donate to a blog, post or target.
+    ///
+    /// The request is validated against the receiving blog's flags (see [`ApiClient::get_blog`]).
+    /// With `dry_run` set, nothing is sent and the built request is returned instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `request`: amount, currency, message and target of the donation.
+    /// - `flags`: flags of the receiving blog.
+    /// - `dry_run`: when `true`, only validate and build the request.
+    ///
+    /// # Returns
+    ///
+    /// [`DonationOutcome::Sent`] with the created donation, or [`DonationOutcome::DryRun`]
+    /// with the request that would have been sent.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `request` fails [`DonationRequest::validate`].
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Donation`.
+    pub async fn donate(
+        &self,
+        request: &DonationRequest,
+        flags: &BlogFlags,
+        dry_run: bool,
+    ) -> ResultApi<DonationOutcome> {
+        request.validate(flags)?;
+
+        let prepared = request.prepare();
+        if dry_run {
+            return Ok(DonationOutcome::DryRun(prepared));
+        }
+
+        let path = &prepared.path;
+        let response = self.post_request(path, &prepared.form, true).await?;
+        let response = self.handle_response(path, response).await?;
+
+        self.parse_json(response).await.map(DonationOutcome::Sent)
+    }
+}
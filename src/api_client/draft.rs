@@ -0,0 +1,130 @@
+use crate::api_client::ApiClient;
+use crate::error::ResultApi;
+use crate::model::{Post, PostCursor, PostsResponse};
+
+impl ApiClient {
+    /// Get a single page of unpublished drafts of a blog, including scheduled posts.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `limit`: number of drafts to fetch in this page.
+    /// - `cursor`: position to continue listing from. `None` starts from the newest draft.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the raw `PostsResponse`. Use [`PostsResponse::next_cursor`]
+    /// to obtain the cursor for the following page.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostsResponse`.
+    pub async fn get_drafts(
+        &self,
+        blog_name: &str,
+        limit: usize,
+        cursor: Option<&PostCursor>,
+    ) -> ResultApi<PostsResponse> {
+        let mut path = format!("blog/{blog_name}/post/draft/?limit={limit}");
+        if let Some(c) = cursor {
+            path.push_str(&format!("&offset={c}"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Publish a draft immediately.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `post_id`: identifier of the draft.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the published `Post`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Post`.
+    pub async fn publish_post(&self, blog_name: &str, post_id: &str) -> ResultApi<Post> {
+        let path = format!("blog/{blog_name}/post/{post_id}/publish/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Schedule a draft to be published by Boosty at `publish_time`.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `post_id`: identifier of the draft.
+    /// - `publish_time`: publication time (Unix epoch seconds).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the scheduled `Post`, with `publish_time` set.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Post`.
+    pub async fn schedule_post(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        publish_time: i64,
+    ) -> ResultApi<Post> {
+        let path = format!("blog/{blog_name}/post/{post_id}/schedule/");
+
+        let response = self
+            .post_request(
+                &path,
+                &serde_json::json!({ "publish_time": publish_time }),
+                true,
+            )
+            .await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Cancel scheduled publication and return the post to drafts.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `post_id`: identifier of the scheduled post.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns `()`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn unschedule_post(&self, blog_name: &str, post_id: &str) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/post/{post_id}/schedule/");
+
+        let response = self.delete_request(&path).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use crate::api_client::{ApiClient, DEFAULT_PAGE_SIZE};
+use crate::error::ResultApi;
+use crate::model::{FeedFilter, Post, PostCursor, PostsResponse};
+
+impl ApiClient {
+    /// Get a single page of the current user's feed across all subscribed and followed blogs.
+    ///
+    /// # Parameters
+    ///
+    /// - `limit`: number of posts to fetch in this page.
+    /// - `cursor`: position to continue listing from. `None` starts from the newest post.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the raw `PostsResponse`. Use [`PostsResponse::next_cursor`]
+    /// to obtain the cursor for the following page.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostsResponse`.
+    pub async fn get_feed_page(
+        &self,
+        limit: usize,
+        cursor: Option<&PostCursor>,
+    ) -> ResultApi<PostsResponse> {
+        let mut path = format!("feed/post/?limit={limit}");
+        if let Some(c) = cursor {
+            path.push_str(&format!("&offset={c}"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get posts from the current user's feed that pass `filter`.
+    ///
+    /// Pages newest-first until `limit` matching posts are collected, the feed ends,
+    /// or a page reaches posts published before `filter.published_from`.
+    ///
+    /// # Parameters
+    ///
+    /// - `limit`: maximum number of matching posts to return.
+    /// - `page_size`: number of posts to fetch per page. Defaults to 20.
+    /// - `filter`: client-side filter applied to every fetched post.
+    /// - `start_cursor`: cursor to start fetching posts after. Defaults from first post.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a vector of matching `Post` items, newest first.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostsResponse`.
+    /// - `ApiError::InvalidCursor` if the API returns a malformed offset.
+    pub async fn get_feed(
+        &self,
+        limit: usize,
+        page_size: Option<usize>,
+        filter: &FeedFilter,
+        start_cursor: Option<PostCursor>,
+    ) -> ResultApi<Vec<Post>> {
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+
+        let mut posts = Vec::new();
+        let mut cursor = start_cursor;
+
+        while posts.len() < limit {
+            let mut page = self.get_feed_page(page_size, cursor.as_ref()).await?;
+
+            let data_len = page.data.len();
+            let mut past_window = false;
+
+            for post in std::mem::take(&mut page.data) {
+                if filter.is_before_window(post.publish_time) {
+                    past_window = true;
+                    break;
+                }
+                if filter.matches(&post) {
+                    posts.push(post);
+                    if posts.len() >= limit {
+                        break;
+                    }
+                }
+            }
+
+            if past_window || data_len == 0 || posts.len() >= limit {
+                break;
+            }
+
+            match page.next_cursor()? {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(posts)
+    }
+}
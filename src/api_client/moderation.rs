@@ -0,0 +1,196 @@
+use crate::{
+    api_client::{ApiClient, paginate_by_offset},
+    error::ResultApi,
+    model::{BlacklistResponse, BlockedUser, Comment},
+};
+
+impl ApiClient {
+    /// Block a user in a blog owned by the current user.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `user_id` - ID of the user to block
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the `BlockedUser` entry.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `BlockedUser`.
+    pub async fn block_user(&self, blog_name: &str, user_id: u64) -> ResultApi<BlockedUser> {
+        let path = format!("blog/{blog_name}/blacklist/");
+
+        let response = self
+            .post_request(&path, &serde_json::json!({ "user_id": user_id }), true)
+            .await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Remove a user from the blog's blacklist.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `user_id` - ID of the blocked user
+    ///
+    /// # Returns
+    ///
+    /// On success, returns `()`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn unblock_user(&self, blog_name: &str, user_id: u64) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/blacklist/{user_id}");
+
+        let response = self.delete_request(&path).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Get a page of users blocked in a blog.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `limit` - Maximum number of users (optional)
+    /// * `offset` - Number of users to skip (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `BlacklistResponse`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `BlacklistResponse`.
+    pub async fn get_blacklist(
+        &self,
+        blog_name: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> ResultApi<BlacklistResponse> {
+        let mut path = format!("blog/{blog_name}/blacklist/");
+        let mut params = Vec::new();
+        if let Some(l) = limit {
+            params.push(format!("limit={l}"));
+        }
+        if let Some(o) = offset {
+            params.push(format!("offset={o}"));
+        }
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get all users blocked in a blog, following offsets until `total` is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `page_size` - Users per request (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns all `BlockedUser` entries.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by `get_blacklist`.
+    /// - `ApiError::Other` if the next offset does not fit in `u32`.
+    pub async fn get_all_blacklist(
+        &self,
+        blog_name: &str,
+        page_size: Option<u32>,
+    ) -> ResultApi<Vec<BlockedUser>> {
+        paginate_by_offset(|offset| async move {
+            let page = self.get_blacklist(blog_name, page_size, offset).await?;
+            Ok((page.data, page.total))
+        })
+        .await
+    }
+
+    /// Hide a comment as the blog owner. The comment stays in the thread with `is_blocked` set.
+    ///
+    /// Use [`ApiClient::delete_comment`] to remove a comment instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `comment_id` - Comment int id
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the hidden `Comment`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Comment`.
+    pub async fn hide_comment(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        comment_id: u64,
+    ) -> ResultApi<Comment> {
+        let path = format!("blog/{blog_name}/post/{post_id}/comment/{comment_id}/block/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Show a previously hidden comment again.
+    ///
+    /// # Arguments
+    ///
+    /// * `blog_name` - Blog name (blog url)
+    /// * `post_id` - Post id
+    /// * `comment_id` - Comment int id
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the restored `Comment`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Comment`.
+    pub async fn unhide_comment(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        comment_id: u64,
+    ) -> ResultApi<Comment> {
+        let path = format!("blog/{blog_name}/post/{post_id}/comment/{comment_id}/block/");
+
+        let response = self.delete_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    api_client::ApiClient,
+    error::ResultApi,
+    model::{Notification, NotificationsResponse},
+};
+
+impl ApiClient {
+    /// Get a page of the current user's notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of notifications (optional)
+    /// * `offset` - Offset from `NotificationsExtra.offset` of the previous page (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `NotificationsResponse`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `NotificationsResponse`.
+    pub async fn get_notifications(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u64>,
+    ) -> ResultApi<NotificationsResponse> {
+        let mut path = "notification/".to_string();
+        let mut params = Vec::new();
+        if let Some(l) = limit {
+            params.push(format!("limit={l}"));
+        }
+        if let Some(o) = offset {
+            params.push(format!("offset={o}"));
+        }
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get all notifications of the current user, following `extra.offset` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Notifications per request (optional)
+    ///
+    /// # Returns
+    ///
+    /// On success, returns all notifications, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by `get_notifications`.
+    pub async fn get_all_notifications(
+        &self,
+        page_size: Option<u32>,
+    ) -> ResultApi<Vec<Notification>> {
+        let mut notifications = Vec::new();
+        let mut offset = None;
+
+        loop {
+            let page = self.get_notifications(page_size, offset).await?;
+            let is_empty = page.data.is_empty();
+            notifications.extend(page.data);
+
+            let next = page.extra.offset;
+            if page.extra.is_last || is_empty || next.is_none() || next == offset {
+                break;
+            }
+            offset = next;
+        }
+
+        Ok(notifications)
+    }
+
+    /// Mark a single notification as read.
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_id` - Notification id
+    ///
+    /// # Returns
+    ///
+    /// On success, returns `()`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn mark_notification_read(&self, notification_id: u64) -> ResultApi<()> {
+        let path = format!("notification/{notification_id}/read/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Mark all notifications of the current user as read.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns `()`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn mark_all_notifications_read(&self) -> ResultApi<()> {
+        let path = "notification/read/";
+
+        let response = self.post_request(path, &(), true).await?;
+        self.handle_response(path, response).await?;
+
+        Ok(())
+    }
+}
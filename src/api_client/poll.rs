@@ -0,0 +1,69 @@
+use crate::api_client::ApiClient;
+use crate::error::ResultApi;
+use crate::model::PollData;
+
+impl ApiClient {
+    /// Get the current state and results of a poll.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `poll_id`: poll ID, from [`PollData::id`].
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the `PollData` with up-to-date vote counts.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PollData`.
+    pub async fn get_poll(&self, blog_name: &str, poll_id: u64) -> ResultApi<PollData> {
+        let path = format!("blog/{blog_name}/poll/{poll_id}/");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Vote in a poll.
+    ///
+    /// The vote is checked against `poll` first (see [`PollData::validate_vote`]).
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `poll`: the poll to vote in, as embedded in a post or returned by [`ApiClient::get_poll`].
+    /// - `option_ids`: chosen option IDs.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the updated `PollData`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the vote is not valid for `poll`.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PollData`.
+    pub async fn vote_poll(
+        &self,
+        blog_name: &str,
+        poll: &PollData,
+        option_ids: &[u64],
+    ) -> ResultApi<PollData> {
+        poll.validate_vote(option_ids)?;
+
+        let path = format!("blog/{blog_name}/poll/{}/vote/", poll.id);
+        let form: Vec<(&str, u64)> = option_ids.iter().map(|&id| ("answer[]", id)).collect();
+
+        let response = self.post_request(&path, &form, true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
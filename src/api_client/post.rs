@@ -1,6 +1,6 @@
 use crate::api_client::{ApiClient, DEFAULT_PAGE_SIZE};
-use crate::error::ResultApi;
-use crate::model::{Post, PostsResponse};
+use crate::error::{ApiError, ResultApi};
+use crate::model::{Post, PostCursor, PostsResponse};
 
 impl ApiClient {
     /// Get a single post once, without automatic retry on "not available" or HTTP 401.
@@ -45,6 +45,42 @@ impl ApiClient {
     //     Ok(posts_response)
     // }
 
+    /// Get a single page of posts for a blog.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `limit`: number of posts to fetch in this page.
+    /// - `cursor`: position to continue listing from. `None` starts from the newest post.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the raw `PostsResponse`. Use [`PostsResponse::next_cursor`]
+    /// to obtain the cursor for the following page.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostsResponse`.
+    pub async fn get_posts_page(
+        &self,
+        blog_name: &str,
+        limit: usize,
+        cursor: Option<&PostCursor>,
+    ) -> ResultApi<PostsResponse> {
+        let mut path = format!("blog/{blog_name}/post/?limit={limit}");
+        if let Some(c) = cursor {
+            path.push_str(&format!("&offset={c}"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
     /// Get multiple posts for a blog.
     ///
     /// # Parameters
@@ -52,51 +88,172 @@ impl ApiClient {
     /// - `blog_name`: blog identifier/name.
     /// - `limit`: number of posts to fetch.
     /// - `page_size`: number of posts to fetch per page. Defaults to 20.
-    /// - `start_offset`: offset to start fetching posts from. Defaults from first post.
+    /// - `start_cursor`: cursor to start fetching posts after. Defaults from first post.
     ///
     /// # Returns
     ///
-    /// On success, returns a `PostsResponse` containing the `data` field with `Post` items.
+    /// On success, returns a vector of `Post` items. The cursor of the last returned post
+    /// (see [`Post::cursor`]) can be passed back as `start_cursor` to resume listing.
     ///
     /// # Errors
     ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
     /// - `ApiError::HttpRequest` if the HTTP request fails.
-    /// - `ApiError::JsonParse` if the HTTP response cannot be parsed as JSON.
-    /// - `ApiError::Deserialization` if the `"data"` field cannot be deserialized into a vector of `Post`
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostsResponse`.
+    /// - `ApiError::InvalidCursor` if the API returns a malformed offset.
     pub async fn get_posts(
         &self,
         blog_name: &str,
         limit: usize,
         page_size: Option<usize>,
-        start_offset: Option<String>,
+        start_cursor: Option<PostCursor>,
     ) -> ResultApi<Vec<Post>> {
         let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
 
         let mut all_posts = Vec::new();
-        let mut offset = start_offset;
+        let mut cursor = start_cursor;
 
         loop {
             let current_limit = page_size.min(limit - all_posts.len());
-            let mut path = format!("blog/{blog_name}/post/?limit={current_limit}");
-            if let Some(ref off) = offset {
-                path.push_str(&format!("&offset={off}"));
+            let mut posts_response = self
+                .get_posts_page(blog_name, current_limit, cursor.as_ref())
+                .await?;
+
+            let data_len = posts_response.data.len();
+            all_posts.append(&mut posts_response.data);
+
+            if all_posts.len() >= limit || data_len == 0 {
+                break;
             }
 
-            let response = self.get_request(&path).await?;
-            let response = self.handle_response(&path, response).await?;
+            match posts_response.next_cursor()? {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
 
-            let posts_response: PostsResponse = self.parse_json(response).await?;
+        Ok(all_posts)
+    }
 
-            let data_len = posts_response.data.len();
-            all_posts.extend(posts_response.data);
+    /// Search posts of a blog by text, on the server.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `query`: text to search for in post titles and content.
+    /// - `limit`: number of posts to fetch in this page.
+    /// - `cursor`: position to continue listing from. `None` starts from the newest match.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the raw `PostsResponse`. Use [`PostsResponse::next_cursor`]
+    /// to obtain the cursor for the following page.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `query` is blank.
+    /// - `ApiError::Serialization` if the query string cannot be encoded.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostsResponse`.
+    pub async fn search_posts(
+        &self,
+        blog_name: &str,
+        query: &str,
+        limit: usize,
+        cursor: Option<&PostCursor>,
+    ) -> ResultApi<PostsResponse> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(ApiError::InvalidInput("search query is empty".into()));
+        }
+
+        let params = serde_urlencoded::to_string([
+            ("query", Some(query.to_string())),
+            ("limit", Some(limit.to_string())),
+            ("offset", cursor.map(|c| c.to_string())),
+        ])?;
+        let path = format!("blog/{blog_name}/post/search/?{params}");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
 
-            if posts_response.extra.is_last || all_posts.len() >= limit || data_len == 0 {
+        self.parse_json(response).await
+    }
+
+    /// Get posts of a blog published between `from` and `to` (inclusive, Unix epoch seconds).
+    ///
+    /// Listing starts at the newest page, since a post's `sort_order` may differ from its
+    /// `publish_time`, and stops at the first post whose `sort_order` and `publish_time` are
+    /// both before `from`. Posts are filtered by `publish_time`.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `from`: earliest publish time.
+    /// - `to`: latest publish time.
+    /// - `page_size`: number of posts to fetch per page. Defaults to 20.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the posts in the window, newest first.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `from` is after `to`.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostsResponse`.
+    /// - `ApiError::InvalidCursor` if the API returns a malformed offset.
+    pub async fn get_posts_between(
+        &self,
+        blog_name: &str,
+        from: i64,
+        to: i64,
+        page_size: Option<usize>,
+    ) -> ResultApi<Vec<Post>> {
+        if from > to {
+            return Err(ApiError::InvalidInput(format!(
+                "invalid time range: {from} is after {to}"
+            )));
+        }
+
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+
+        let mut posts = Vec::new();
+        let mut cursor: Option<PostCursor> = None;
+
+        loop {
+            let mut page = self
+                .get_posts_page(blog_name, page_size, cursor.as_ref())
+                .await?;
+
+            let data_len = page.data.len();
+            let mut left_window = false;
+
+            for post in std::mem::take(&mut page.data) {
+                if post.sort_order < from && post.publish_time < from {
+                    left_window = true;
+                    break;
+                }
+                if (from..=to).contains(&post.publish_time) {
+                    posts.push(post);
+                }
+            }
+
+            if left_window || data_len == 0 {
                 break;
             }
 
-            offset = Some(posts_response.extra.offset);
+            match page.next_cursor()? {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
         }
 
-        Ok(all_posts)
+        Ok(posts)
     }
 }
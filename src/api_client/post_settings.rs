@@ -0,0 +1,99 @@
+use crate::api_client::ApiClient;
+use crate::error::ResultApi;
+use crate::model::{Post, PostAccess, PostSettings};
+
+impl ApiClient {
+    /// Change display and interaction flags of a post.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `post_id`: identifier of the post.
+    /// - `settings`: flags to change (pin, showcase visibility, comments, reactions).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the updated `Post`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `settings` changes nothing.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Post`.
+    pub async fn update_post_settings(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        settings: &PostSettings,
+    ) -> ResultApi<Post> {
+        settings.validate()?;
+
+        let path = format!("blog/{blog_name}/post/{post_id}/settings/");
+
+        let response = self.put_request(&path, settings, false).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Pin a post to the top of the blog.
+    ///
+    /// Shorthand for [`ApiClient::update_post_settings`] with `PostSettings::new().pinned(true)`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ApiClient::update_post_settings`].
+    pub async fn pin_post(&self, blog_name: &str, post_id: &str) -> ResultApi<Post> {
+        self.update_post_settings(blog_name, post_id, &PostSettings::new().pinned(true))
+            .await
+    }
+
+    /// Unpin a post.
+    ///
+    /// Shorthand for [`ApiClient::update_post_settings`] with `PostSettings::new().pinned(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ApiClient::update_post_settings`].
+    pub async fn unpin_post(&self, blog_name: &str, post_id: &str) -> ResultApi<Post> {
+        self.update_post_settings(blog_name, post_id, &PostSettings::new().pinned(false))
+            .await
+    }
+
+    /// Move a post to a different subscription level, make it free, or sell it at a price.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: blog identifier/name.
+    /// - `post_id`: identifier of the post.
+    /// - `access`: new access rule.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the updated `Post`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `access` is invalid (see [`PostAccess::validate`]).
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Post`.
+    pub async fn set_post_access(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        access: PostAccess,
+    ) -> ResultApi<Post> {
+        access.validate()?;
+
+        let path = format!("blog/{blog_name}/post/{post_id}/access/");
+
+        let response = self.put_request(&path, &access.to_form(), true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
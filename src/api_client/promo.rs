@@ -0,0 +1,129 @@
+use crate::api_client::ApiClient;
+use crate::error::ResultApi;
+use crate::model::{NewPromo, Promo, PromosResponse};
+
+impl ApiClient {
+    /// Create a discount promo for a subscription level.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level_id`: ID of the subscription level the promo applies to.
+    /// - `promo`: discount percent, start/end time, activation limit and audience of the promo.
+    ///
+    /// # Returns
+    ///
+    /// The created [`Promo`].
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `promo` fails [`NewPromo::validate`].
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Promo`.
+    pub async fn create_promo(
+        &self,
+        blog_name: &str,
+        level_id: u64,
+        promo: &NewPromo,
+    ) -> ResultApi<Promo> {
+        promo.validate()?;
+
+        let path = format!("blog/{blog_name}/subscription_level/{level_id}/promo/");
+
+        let response = self.post_request(&path, &promo_form(promo), true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Get all promos of a subscription level, including finished ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level_id`: ID of the subscription level.
+    ///
+    /// # Returns
+    ///
+    /// A [`PromosResponse`] containing the `"data"` array with promos.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PromosResponse`.
+    pub async fn get_promos(&self, blog_name: &str, level_id: u64) -> ResultApi<PromosResponse> {
+        let path = format!("blog/{blog_name}/subscription_level/{level_id}/promo/");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Finish a promo before its end time.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level_id`: ID of the subscription level.
+    /// - `promo_id`: ID of the promo to finish.
+    ///
+    /// # Returns
+    ///
+    /// The finished [`Promo`], with `is_finished` set.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `Promo`.
+    pub async fn finish_promo(
+        &self,
+        blog_name: &str,
+        level_id: u64,
+        promo_id: u64,
+    ) -> ResultApi<Promo> {
+        let path =
+            format!("blog/{blog_name}/subscription_level/{level_id}/promo/{promo_id}/finish/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
+
+/// Build the form fields for creating a promo.
+fn promo_form(promo: &NewPromo) -> Vec<(&'static str, String)> {
+    let access = &promo.access;
+    let mut form = vec![
+        ("discount_percent", promo.discount_percent.to_string()),
+        ("start_time", promo.start_time.to_string()),
+        (
+            "access_other_level_subscriber",
+            access.access_other_level_subscriber.to_string(),
+        ),
+        ("new_subscriber", access.new_subscriber.to_string()),
+        (
+            "old_paid_subscriber",
+            access.old_paid_subscriber.to_string(),
+        ),
+    ];
+
+    if let Some(end_time) = promo.end_time {
+        form.push(("end_time", end_time.to_string()));
+    }
+    if let Some(max_activation) = promo.max_activation {
+        form.push(("max_activation", max_activation.to_string()));
+    }
+    if let Some(description) = &promo.description {
+        form.push(("description", description.clone()));
+    }
+
+    form
+}
@@ -0,0 +1,180 @@
+use crate::{api_client::ApiClient, error::ResultApi, model::ReactionKind};
+
+impl ApiClient {
+    /// Set a reaction on a post, replacing the current user's previous reaction.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `post_id` - Post id
+    /// * `kind` - Reaction kind
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn set_post_reaction(
+        &self,
+        blog_name: &str,
+        post_id: &str,
+        kind: &ReactionKind,
+    ) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/post/{post_id}/reaction/");
+        self.send_reaction(&path, kind).await
+    }
+
+    /// Remove the current user's reaction from a post.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `post_id` - Post id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn remove_post_reaction(&self, blog_name: &str, post_id: &str) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/post/{post_id}/reaction/");
+        self.send_reaction_removal(&path).await
+    }
+
+    /// Like a post.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `post_id` - Post id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn like_post(&self, blog_name: &str, post_id: &str) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/post/{post_id}/like/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Remove the current user's like from a post.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `post_id` - Post id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn unlike_post(&self, blog_name: &str, post_id: &str) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/post/{post_id}/like/");
+        self.send_reaction_removal(&path).await
+    }
+
+    /// Set a reaction on a comment, replacing the current user's previous reaction.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `comment_id` - Comment int id
+    /// * `kind` - Reaction kind
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn set_comment_reaction(
+        &self,
+        blog_name: &str,
+        comment_id: u64,
+        kind: &ReactionKind,
+    ) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/comment/{comment_id}/reaction/");
+        self.send_reaction(&path, kind).await
+    }
+
+    /// Remove the current user's reaction from a comment.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `comment_id` - Comment int id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn remove_comment_reaction(&self, blog_name: &str, comment_id: u64) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/comment/{comment_id}/reaction/");
+        self.send_reaction_removal(&path).await
+    }
+
+    /// Like a comment.
+    ///
+    /// Comments have no separate like counter, so this sets a `ReactionKind::Like` reaction.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `comment_id` - Comment int id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn like_comment(&self, blog_name: &str, comment_id: u64) -> ResultApi<()> {
+        self.set_comment_reaction(blog_name, comment_id, &ReactionKind::Like)
+            .await
+    }
+
+    /// Remove the current user's like from a comment.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `comment_id` - Comment int id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn unlike_comment(&self, blog_name: &str, comment_id: u64) -> ResultApi<()> {
+        self.remove_comment_reaction(blog_name, comment_id).await
+    }
+
+    async fn send_reaction(&self, path: &str, kind: &ReactionKind) -> ResultApi<()> {
+        let response = self
+            .post_request(path, &serde_json::json!({ "type": kind }), true)
+            .await?;
+        self.handle_response(path, response).await?;
+
+        Ok(())
+    }
+
+    async fn send_reaction_removal(&self, path: &str) -> ResultApi<()> {
+        let response = self.delete_request(path).await?;
+        self.handle_response(path, response).await?;
+
+        Ok(())
+    }
+}
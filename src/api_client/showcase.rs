@@ -1,4 +1,10 @@
-use crate::{api_client::ApiClient, error::ResultApi, model::ShowcaseResponse};
+use std::collections::HashSet;
+
+use crate::{
+    api_client::ApiClient,
+    error::{ApiError, ResultApi},
+    model::{ShowcaseItem, ShowcaseItemType, ShowcaseResponse},
+};
 
 impl ApiClient {
     /// Get blog showcase
@@ -72,4 +78,191 @@ impl ApiClient {
 
         Ok(())
     }
+
+    /// Get all showcase items, following `Extra.offset` until the last page.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `page_size` - Limit per request
+    /// * `only_visible` - Only visible
+    ///
+    /// # Returns
+    /// * On success, returns all `ShowcaseItem` items in page order.
+    ///
+    /// # Errors
+    /// * Any error returned by `get_showcase`.
+    /// * `ApiError::Other` if the API returns a negative or too large offset.
+    pub async fn get_all_showcase_items(
+        &self,
+        blog_name: &str,
+        page_size: Option<u32>,
+        only_visible: Option<bool>,
+    ) -> ResultApi<Vec<ShowcaseItem>> {
+        let mut items = Vec::new();
+        let mut offset = None;
+
+        loop {
+            let page = self
+                .get_showcase(blog_name, page_size, only_visible, offset)
+                .await?;
+            let page_len = page.data.showcase_items.len();
+            items.extend(page.data.showcase_items);
+
+            if page.extra.is_last || page_len == 0 {
+                break;
+            }
+            let next = u32::try_from(page.extra.offset).map_err(|_| {
+                ApiError::Other(format!("invalid showcase offset: {}", page.extra.offset))
+            })?;
+            if offset == Some(next) {
+                break;
+            }
+            offset = Some(next);
+        }
+
+        Ok(items)
+    }
+
+    /// Add a post or bundle to the showcase
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `item_type` - Type of the item
+    /// * `item_id` - Post id or bundle UUID
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::InvalidInput` if `item_type` is `ShowcaseItemType::Unknown`.
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn add_showcase_item(
+        &self,
+        blog_name: &str,
+        item_type: ShowcaseItemType,
+        item_id: &str,
+    ) -> ResultApi<()> {
+        if item_type == ShowcaseItemType::Unknown {
+            return Err(ApiError::InvalidInput(
+                "cannot add showcase item of unknown type".into(),
+            ));
+        }
+
+        let path = format!("blog/{blog_name}/showcase/");
+
+        let response = self
+            .post_request(
+                &path,
+                &serde_json::json!({"item_type": item_type, "item_id": item_id}),
+                true,
+            )
+            .await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Remove an item from the showcase
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `showcase_item_id` - Showcase item id
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn remove_showcase_item(
+        &self,
+        blog_name: &str,
+        showcase_item_id: i64,
+    ) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/showcase/{showcase_item_id}");
+
+        let response = self.delete_request(&path).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Show or hide a single showcase item
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `showcase_item_id` - Showcase item id
+    /// * `is_visible` - Visibility (true to show, false to hide)
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn set_showcase_item_visibility(
+        &self,
+        blog_name: &str,
+        showcase_item_id: i64,
+        is_visible: bool,
+    ) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/showcase/{showcase_item_id}");
+
+        let response = self
+            .put_request(&path, &serde_json::json!({"is_visible": is_visible}), true)
+            .await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Reorder showcase items in a single request
+    ///
+    /// Items are placed in the order of `showcase_item_ids`; the API applies the
+    /// whole order at once, so the showcase is never left partially reordered.
+    ///
+    /// # Arguments
+    /// * `blog_name` - Blog name
+    /// * `showcase_item_ids` - Showcase item ids in the new order
+    ///
+    /// # Returns
+    /// * On success, returns `()`.
+    ///
+    /// # Errors
+    /// * `ApiError::InvalidInput` if `showcase_item_ids` is empty or contains duplicates.
+    /// * `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// * `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// * `ApiError::HttpRequest` if the HTTP request fails.
+    pub async fn reorder_showcase_items(
+        &self,
+        blog_name: &str,
+        showcase_item_ids: &[i64],
+    ) -> ResultApi<()> {
+        if showcase_item_ids.is_empty() {
+            return Err(ApiError::InvalidInput("showcase order is empty".into()));
+        }
+        let mut seen = HashSet::new();
+        if let Some(id) = showcase_item_ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(ApiError::InvalidInput(format!(
+                "duplicate showcase item id in order: {id}"
+            )));
+        }
+
+        let path = format!("blog/{blog_name}/showcase/order/");
+
+        let response = self
+            .put_request(
+                &path,
+                &serde_json::json!({"showcase_item_ids": showcase_item_ids}),
+                false,
+            )
+            .await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
 }
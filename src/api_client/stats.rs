@@ -0,0 +1,155 @@
+use crate::api_client::ApiClient;
+use crate::error::ResultApi;
+use crate::model::{
+    IncomeResponse, IncomeSummary, PostViewsResponse, SubscriberStatsResponse, TimeBucket,
+};
+
+impl ApiClient {
+    /// Fetch income records of the owner's blog for a period.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `from`: start of the period (Unix epoch, inclusive).
+    /// - `to`: end of the period (Unix epoch, exclusive).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns an `IncomeResponse` with one record per payment.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into an `IncomeResponse`.
+    pub async fn get_income_stats(
+        &self,
+        blog_name: &str,
+        from: i64,
+        to: i64,
+    ) -> ResultApi<IncomeResponse> {
+        let path = format!("blog/{blog_name}/stats/income/?from={from}&to={to}");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Fetch income for a period and sum it by source.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `from`: start of the period (Unix epoch, inclusive).
+    /// - `to`: end of the period (Unix epoch, exclusive).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns the [`IncomeSummary`] of the period.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by `get_income_stats`.
+    pub async fn get_income_summary(
+        &self,
+        blog_name: &str,
+        from: i64,
+        to: i64,
+    ) -> ResultApi<IncomeSummary> {
+        let income = self.get_income_stats(blog_name, from, to).await?;
+        Ok(IncomeSummary::from_entries(&income.data))
+    }
+
+    /// Fetch income for a period and sum it per day, week or month.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `from`: start of the period (Unix epoch, inclusive).
+    /// - `to`: end of the period (Unix epoch, exclusive).
+    /// - `bucket`: length of each reporting period.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns `(bucket start, summary)` pairs in chronological order.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by `get_income_stats`.
+    pub async fn get_income_report(
+        &self,
+        blog_name: &str,
+        from: i64,
+        to: i64,
+        bucket: TimeBucket,
+    ) -> ResultApi<Vec<(i64, IncomeSummary)>> {
+        let income = self.get_income_stats(blog_name, from, to).await?;
+        Ok(IncomeSummary::bucketed(&income.data, bucket))
+    }
+
+    /// Fetch subscriber and follower counts of the owner's blog over a period.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `from`: start of the period (Unix epoch, inclusive).
+    /// - `to`: end of the period (Unix epoch, exclusive).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `SubscriberStatsResponse` with data points in chronological order.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `SubscriberStatsResponse`.
+    pub async fn get_subscriber_stats(
+        &self,
+        blog_name: &str,
+        from: i64,
+        to: i64,
+    ) -> ResultApi<SubscriberStatsResponse> {
+        let path = format!("blog/{blog_name}/stats/subscribers/?from={from}&to={to}");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Fetch per-post view counts of the owner's blog for a period.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `from`: start of the period (Unix epoch, inclusive).
+    /// - `to`: end of the period (Unix epoch, exclusive).
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `PostViewsResponse` with one entry per post.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `PostViewsResponse`.
+    pub async fn get_post_views_stats(
+        &self,
+        blog_name: &str,
+        from: i64,
+        to: i64,
+    ) -> ResultApi<PostViewsResponse> {
+        let path = format!("blog/{blog_name}/stats/posts/?from={from}&to={to}");
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
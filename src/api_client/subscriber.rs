@@ -0,0 +1,84 @@
+use crate::api_client::{ApiClient, paginate_by_offset};
+use crate::error::ResultApi;
+use crate::model::{Subscriber, SubscribersResponse};
+
+impl ApiClient {
+    /// Fetch one page of the blog's subscribers. Only available to the blog owner.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `limit`: optional maximum number of subscribers to fetch.
+    /// - `offset`: optional number of subscribers to skip, for paging.
+    /// - `with_follow`: when `Some(true)`, include free followers.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `SubscribersResponse` containing the list of subscribers and pagination info.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `SubscribersResponse`.
+    pub async fn get_blog_subscribers(
+        &self,
+        blog_name: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        with_follow: Option<bool>,
+    ) -> ResultApi<SubscribersResponse> {
+        let mut path = format!("blog/{blog_name}/subscribers/");
+        let mut params = Vec::new();
+        if let Some(l) = limit {
+            params.push(format!("limit={l}"));
+        }
+        if let Some(o) = offset {
+            params.push(format!("offset={o}"));
+        }
+        if let Some(f) = with_follow {
+            params.push(format!("with_follow={f}"));
+        }
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        let response = self.get_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Fetch all of the blog's subscribers, following `offset` until `total` is reached.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `page_size`: optional number of subscribers per request.
+    /// - `with_follow`: when `Some(true)`, include free followers.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns all subscribers in page order.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by `get_blog_subscribers`.
+    /// - `ApiError::Other` if the next offset does not fit in `u32`.
+    pub async fn get_all_blog_subscribers(
+        &self,
+        blog_name: &str,
+        page_size: Option<u32>,
+        with_follow: Option<bool>,
+    ) -> ResultApi<Vec<Subscriber>> {
+        paginate_by_offset(|offset| async move {
+            let page = self
+                .get_blog_subscribers(blog_name, page_size, offset, with_follow)
+                .await?;
+            Ok((page.data, page.total))
+        })
+        .await
+    }
+}
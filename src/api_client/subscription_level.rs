@@ -1,6 +1,9 @@
+use reqwest::multipart::Form;
+
 use crate::api_client::ApiClient;
+use crate::api_client::comment::append_blocks;
 use crate::error::ResultApi;
-use crate::model::SubscriptionLevelResponse;
+use crate::model::{NewSubscriptionLevel, SubscriptionLevel, SubscriptionLevelResponse};
 
 impl ApiClient {
     /// Fetch subscription levels for a blog, with optional inclusion of the free level.
@@ -36,4 +39,185 @@ impl ApiClient {
 
         self.parse_json(response).await
     }
+
+    /// Create a new subscription level for a blog.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level`: name, price, description blocks and subscribers limit of the new level.
+    ///
+    /// # Returns
+    ///
+    /// The created [`SubscriptionLevel`].
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `level` fails [`NewSubscriptionLevel::validate`].
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `SubscriptionLevel`.
+    pub async fn create_subscription_level(
+        &self,
+        blog_name: &str,
+        level: &NewSubscriptionLevel,
+    ) -> ResultApi<SubscriptionLevel> {
+        let path = format!("blog/{blog_name}/subscription_level/");
+        let form = subscription_level_form(level)?;
+
+        let response = self.post_multipart(&path, form).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Update an existing subscription level.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level_id`: ID of the level to update.
+    /// - `level`: new name, price, description blocks and subscribers limit.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`SubscriptionLevel`].
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if `level` fails [`NewSubscriptionLevel::validate`].
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `SubscriptionLevel`.
+    pub async fn update_subscription_level(
+        &self,
+        blog_name: &str,
+        level_id: u64,
+        level: &NewSubscriptionLevel,
+    ) -> ResultApi<SubscriptionLevel> {
+        let path = format!("blog/{blog_name}/subscription_level/{level_id}");
+        let form = subscription_level_form(level)?;
+
+        let response = self.put_multipart(&path, form).await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Archive or unarchive a subscription level.
+    ///
+    /// Archived levels stay active for current subscribers but cannot be bought.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level_id`: ID of the level.
+    /// - `is_archived`: `true` to archive, `false` to restore.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`SubscriptionLevel`].
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `SubscriptionLevel`.
+    pub async fn archive_subscription_level(
+        &self,
+        blog_name: &str,
+        level_id: u64,
+        is_archived: bool,
+    ) -> ResultApi<SubscriptionLevel> {
+        let path = format!("blog/{blog_name}/subscription_level/{level_id}/archive/");
+
+        let response = self
+            .put_request(
+                &path,
+                &serde_json::json!({"is_archived": is_archived}),
+                true,
+            )
+            .await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Hide or show a subscription level on the blog page.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level_id`: ID of the level.
+    /// - `is_hidden`: `true` to hide, `false` to show.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`SubscriptionLevel`].
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into a `SubscriptionLevel`.
+    pub async fn hide_subscription_level(
+        &self,
+        blog_name: &str,
+        level_id: u64,
+        is_hidden: bool,
+    ) -> ResultApi<SubscriptionLevel> {
+        let path = format!("blog/{blog_name}/subscription_level/{level_id}/hide/");
+
+        let response = self
+            .put_request(&path, &serde_json::json!({"is_hidden": is_hidden}), true)
+            .await?;
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+
+    /// Delete a subscription level.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    /// - `level_id`: ID of the level to delete.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    pub async fn delete_subscription_level(&self, blog_name: &str, level_id: u64) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/subscription_level/{level_id}");
+
+        let response = self.delete_request(&path).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+}
+
+/// Build the multipart form for creating or updating a subscription level.
+fn subscription_level_form(level: &NewSubscriptionLevel) -> ResultApi<Form> {
+    level.validate()?;
+
+    let mut form = Form::new()
+        .text("name", level.name.clone())
+        .text("price", level.price.to_string())
+        .text("is_limited", level.subscribers_limit.is_some().to_string());
+
+    if let Some(limit) = level.subscribers_limit {
+        form = form.text("limit", limit.to_string());
+    }
+
+    append_blocks(form, &level.description)
 }
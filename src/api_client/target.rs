@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::api_client::ApiClient;
 use crate::error::{ApiError, ResultApi};
 use crate::model::{NewTarget, Target, TargetResponse, TargetType, UpdateTarget};
@@ -81,12 +83,15 @@ impl ApiClient {
     ///
     /// # Errors
     ///
+    /// - [`ApiError::Unauthorized`] — if the HTTP status is 401 Unauthorized.
+    /// - [`ApiError::HttpStatus`] — for other non-success HTTP statuses.
     /// - [`ApiError::HttpRequest`] — if the network request fails.
     /// - [`ApiError::JsonParse`] — if the response body cannot be parsed as JSON (rare for DELETE).
     pub async fn delete_blog_target(&self, target_id: u64) -> ResultApi<()> {
         let path = format!("target/{}", target_id);
 
         let response = self.delete_request(&path).await?;
+        let response = self.handle_response(&path, response).await?;
 
         let _ = response
             .json::<serde_json::Value>()
@@ -131,4 +136,48 @@ impl ApiClient {
 
         self.parse_json(response).await
     }
+
+    /// Change the priority order of a blog's targets.
+    ///
+    /// The first ID gets the highest priority. The whole order is sent in one request.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: identifier or slug of the blog owning the targets.
+    /// - `target_ids`: IDs of the targets in the new priority order.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError::InvalidInput`] — if `target_ids` is empty or contains duplicates.
+    /// - [`ApiError::Unauthorized`] — if the HTTP status is 401 Unauthorized.
+    /// - [`ApiError::HttpStatus`] — for other non-success HTTP statuses.
+    /// - [`ApiError::HttpRequest`] — if the network request fails.
+    pub async fn reorder_blog_targets(&self, blog_name: &str, target_ids: &[u64]) -> ResultApi<()> {
+        if target_ids.is_empty() {
+            return Err(ApiError::InvalidInput("target order is empty".into()));
+        }
+        let mut seen = HashSet::new();
+        if let Some(id) = target_ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(ApiError::InvalidInput(format!(
+                "duplicate target id in order: {id}"
+            )));
+        }
+
+        let path = format!("target/{blog_name}/order/");
+
+        let response = self
+            .put_request(
+                &path,
+                &serde_json::json!({ "target_ids": target_ids }),
+                false,
+            )
+            .await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
 }
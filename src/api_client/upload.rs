@@ -0,0 +1,185 @@
+use std::{
+    io,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+};
+
+use futures_util::{StreamExt, stream};
+use reqwest::{
+    Body,
+    multipart::{Form, Part},
+};
+use serde::de::DeserializeOwned;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+use crate::{
+    api_client::ApiClient,
+    error::{ApiError, ResultApi},
+    model::{MediaUpload, UploadKind, UploadSource, UploadedAudio, UploadedFile, UploadedImage},
+};
+
+impl ApiClient {
+    /// Upload an image for use in post, comment or message content.
+    ///
+    /// # Parameters
+    ///
+    /// - `upload`: image source, from a path, bytes or an async reader.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns an `UploadedImage`, which converts into a `CommentBlock`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the image is empty, larger than `MAX_IMAGE_SIZE`
+    ///   or not a JPEG, PNG, GIF or WebP image.
+    /// - `ApiError::InvalidInput` if a reader yields more or fewer bytes than its declared length.
+    /// - `ApiError::Io` if the source file cannot be opened.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into an `UploadedImage`.
+    pub async fn upload_image(&self, upload: MediaUpload) -> ResultApi<UploadedImage> {
+        self.upload_media(UploadKind::Image, upload).await
+    }
+
+    /// Upload an audio file for use in post or comment content.
+    ///
+    /// # Parameters
+    ///
+    /// - `upload`: audio source, from a path, bytes or an async reader.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns an `UploadedAudio`, which converts into a `CommentBlock`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the audio is empty, larger than `MAX_AUDIO_SIZE`
+    ///   or not an MP3, M4A, OGG, WAV or FLAC file.
+    /// - `ApiError::InvalidInput` if a reader yields more or fewer bytes than its declared length.
+    /// - `ApiError::Io` if the source file cannot be opened.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into an `UploadedAudio`.
+    pub async fn upload_audio(&self, upload: MediaUpload) -> ResultApi<UploadedAudio> {
+        self.upload_media(UploadKind::Audio, upload).await
+    }
+
+    /// Upload an arbitrary file for use in post or comment content.
+    ///
+    /// # Parameters
+    ///
+    /// - `upload`: file source, from a path, bytes or an async reader.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns an `UploadedFile`, which converts into a `CommentBlock`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the file is empty or larger than `MAX_FILE_SIZE`.
+    /// - `ApiError::InvalidInput` if a reader yields more or fewer bytes than its declared length.
+    /// - `ApiError::Io` if the source file cannot be opened.
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the HTTP request fails.
+    /// - `ApiError::JsonParseDetailed` if the response body cannot be parsed into an `UploadedFile`.
+    pub async fn upload_file(&self, upload: MediaUpload) -> ResultApi<UploadedFile> {
+        self.upload_media(UploadKind::File, upload).await
+    }
+
+    /// Validate `upload` for `kind` and stream it as the `file` part of a multipart POST
+    /// to `media/{kind}/`, reporting progress as chunks are sent.
+    async fn upload_media<T: DeserializeOwned>(
+        &self,
+        kind: UploadKind,
+        upload: MediaUpload,
+    ) -> ResultApi<T> {
+        let path = format!("media/{}/", kind.as_str());
+
+        let length = match &upload.source {
+            UploadSource::Path(file_path) => tokio::fs::metadata(file_path).await?.len(),
+            UploadSource::Bytes(bytes) => bytes.len() as u64,
+            UploadSource::Reader { length, .. } => *length,
+        };
+        upload.validate(kind, length)?;
+
+        let mime_type = upload.mime_type().to_string();
+        let MediaUpload {
+            file_name,
+            source,
+            progress,
+            ..
+        } = upload;
+
+        let reader: Box<dyn AsyncRead + Send + Unpin> = match source {
+            UploadSource::Path(file_path) => Box::new(tokio::fs::File::open(file_path).await?),
+            UploadSource::Bytes(bytes) => Box::new(std::io::Cursor::new(bytes)),
+            UploadSource::Reader { reader, .. } => reader,
+        };
+
+        // The declared length goes into the multipart headers, so the source must
+        // yield exactly that many bytes; fail the body stream otherwise.
+        let sent = Arc::new(AtomicU64::new(0));
+        let mismatch = Arc::new(AtomicBool::new(false));
+        let counted = ReaderStream::new(reader).map({
+            let sent = Arc::clone(&sent);
+            let mismatch = Arc::clone(&mismatch);
+            move |chunk| {
+                let bytes = chunk?;
+                let total =
+                    sent.fetch_add(bytes.len() as u64, Ordering::Relaxed) + bytes.len() as u64;
+                if total > length {
+                    mismatch.store(true, Ordering::Relaxed);
+                    return Err(length_mismatch(length));
+                }
+                if let Some(report) = &progress {
+                    report(total, length);
+                }
+                Ok(bytes)
+            }
+        });
+        let tail = stream::once({
+            let sent = Arc::clone(&sent);
+            let mismatch = Arc::clone(&mismatch);
+            async move {
+                let short = sent.load(Ordering::Relaxed) < length;
+                mismatch.store(short, Ordering::Relaxed);
+                short
+            }
+        })
+        .filter_map(move |short| async move { short.then(|| Err(length_mismatch(length))) });
+
+        let part = Part::stream_with_length(Body::wrap_stream(counted.chain(tail)), length)
+            .file_name(file_name)
+            .mime_str(&mime_type)
+            .map_err(|e| ApiError::Other(e.to_string()))?;
+        let form = Form::new().part("file", part);
+
+        let response = match self.post_multipart(&path, form).await {
+            Ok(response) => response,
+            Err(_) if mismatch.load(Ordering::Relaxed) => {
+                let sent = sent.load(Ordering::Relaxed);
+                return Err(ApiError::InvalidInput(format!(
+                    "upload source yielded {sent} bytes, expected {length}"
+                )));
+            }
+            Err(e) => return Err(e),
+        };
+        let response = self.handle_response(&path, response).await?;
+
+        self.parse_json(response).await
+    }
+}
+
+fn length_mismatch(length: u64) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("upload source length does not match declared length {length}"),
+    )
+}
@@ -1,6 +1,6 @@
-use crate::api_client::ApiClient;
+use crate::api_client::{ApiClient, paginate_by_offset};
 use crate::error::ResultApi;
-use crate::model::SubscriptionsResponse;
+use crate::model::{Subscription, SubscriptionsResponse};
 
 impl ApiClient {
     /// Fetch the current user's subscriptions, with optional pagination and follow filter.
@@ -28,6 +28,33 @@ impl ApiClient {
         &self,
         limit: Option<u32>,
         with_follow: Option<bool>,
+    ) -> ResultApi<SubscriptionsResponse> {
+        self.get_user_subscriptions_page(limit, with_follow, None)
+            .await
+    }
+
+    /// Fetch one page of the current user's subscriptions, starting at `offset`.
+    ///
+    /// Same as `get_user_subscriptions`, with an additional `offset` query parameter.
+    ///
+    /// # Parameters
+    ///
+    /// - `limit`: optional maximum number of subscriptions to fetch.
+    /// - `with_follow`: optional flag to include subscriptions on followed blogs.
+    /// - `offset`: optional number of subscriptions to skip.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `SubscriptionsResponse` containing the list of subscriptions and pagination info.
+    ///
+    /// # Errors
+    ///
+    /// Same as `get_user_subscriptions`.
+    pub async fn get_user_subscriptions_page(
+        &self,
+        limit: Option<u32>,
+        with_follow: Option<bool>,
+        offset: Option<u32>,
     ) -> ResultApi<SubscriptionsResponse> {
         let mut path = "user/subscriptions".to_string();
         let mut params = Vec::new();
@@ -37,6 +64,9 @@ impl ApiClient {
         if let Some(f) = with_follow {
             params.push(format!("with_follow={f}"));
         }
+        if let Some(o) = offset {
+            params.push(format!("offset={o}"));
+        }
         if !params.is_empty() {
             path.push('?');
             path.push_str(&params.join("&"));
@@ -47,4 +77,81 @@ impl ApiClient {
 
         self.parse_json(response).await
     }
+
+    /// Fetch all of the current user's subscriptions, following `offset` until `total` is reached.
+    ///
+    /// # Parameters
+    ///
+    /// - `page_size`: optional number of subscriptions per request.
+    /// - `with_follow`: optional flag to include subscriptions on followed blogs.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns all subscriptions in page order.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by `get_user_subscriptions_page`.
+    /// - `ApiError::Other` if the next offset does not fit in `u32`.
+    pub async fn get_all_user_subscriptions(
+        &self,
+        page_size: Option<u32>,
+        with_follow: Option<bool>,
+    ) -> ResultApi<Vec<Subscription>> {
+        paginate_by_offset(|offset| async move {
+            let page = self
+                .get_user_subscriptions_page(page_size, with_follow, offset)
+                .await?;
+            Ok((page.data, page.total))
+        })
+        .await
+    }
+
+    /// Follow a blog for free, without a paid subscription.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    pub async fn follow_blog(&self, blog_name: &str) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/follow/");
+
+        let response = self.post_request(&path, &(), true).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
+
+    /// Stop following a blog.
+    ///
+    /// # Parameters
+    ///
+    /// - `blog_name`: the identifier or name of the blog.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::Unauthorized` if the HTTP status is 401 Unauthorized.
+    /// - `ApiError::HttpStatus` for other non-success HTTP statuses, with status and endpoint info.
+    /// - `ApiError::HttpRequest` if the network request fails.
+    pub async fn unfollow_blog(&self, blog_name: &str) -> ResultApi<()> {
+        let path = format!("blog/{blog_name}/follow/");
+
+        let response = self.delete_request(&path).await?;
+        self.handle_response(&path, response).await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,352 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::model::Comment;
+
+/// Threaded view over a flat list of comments.
+///
+/// Comments are linked to their parent by `reply_id` (the comment being answered)
+/// and, if that comment is not present, by `parent_id` (the thread root).
+/// Comments whose parent cannot be found become roots. If parent links form a
+/// cycle, the earliest supplied comment of the cycle becomes a root.
+///
+/// Nested `replies` pages are flattened into the tree on construction, so every
+/// stored `Comment` has `replies == None`.
+#[derive(Debug, Default)]
+pub struct CommentTree {
+    comments: Vec<Comment>,
+    children: Vec<Vec<usize>>,
+    parents: Vec<Option<usize>>,
+    roots: Vec<usize>,
+    index: HashMap<u64, usize>,
+}
+
+impl CommentTree {
+    /// Build a tree from comments, including any replies nested inside them.
+    ///
+    /// Duplicates (same `int_id`) are dropped, keeping the first occurrence.
+    /// Roots keep the order in which they were supplied; replies are ordered
+    /// by `created_at`.
+    pub fn from_comments(comments: Vec<Comment>) -> Self {
+        let mut tree = CommentTree::default();
+        for comment in comments {
+            tree.insert_flattened(comment);
+        }
+
+        tree.children = vec![Vec::new(); tree.comments.len()];
+        tree.parents = vec![None; tree.comments.len()];
+
+        for idx in 0..tree.comments.len() {
+            match tree.find_parent(idx) {
+                Some(parent) => {
+                    tree.children[parent].push(idx);
+                    tree.parents[idx] = Some(parent);
+                }
+                None => tree.roots.push(idx),
+            }
+        }
+
+        tree.promote_cycles();
+
+        let comments = &tree.comments;
+        for children in &mut tree.children {
+            children.sort_by_key(|&idx| comments[idx].created_at);
+        }
+
+        tree
+    }
+
+    fn insert_flattened(&mut self, comment: Comment) {
+        let mut stack = vec![comment];
+
+        while let Some(mut comment) = stack.pop() {
+            if let Some(replies) = comment.replies.take() {
+                stack.extend(replies.data.into_iter().rev());
+            }
+
+            if !self.index.contains_key(&comment.int_id) {
+                self.index.insert(comment.int_id, self.comments.len());
+                self.comments.push(comment);
+            }
+        }
+    }
+
+    /// Detach comments caught in parent cycles so every comment is reachable from a root.
+    fn promote_cycles(&mut self) {
+        let mut reachable = vec![false; self.comments.len()];
+        let mut stack = self.roots.clone();
+
+        loop {
+            while let Some(idx) = stack.pop() {
+                reachable[idx] = true;
+                stack.extend(self.children[idx].iter().copied());
+            }
+
+            let Some(start) = reachable.iter().position(|seen| !seen) else {
+                break;
+            };
+
+            // Walk up from an unreachable comment until a comment repeats; that one
+            // is on the cycle. Promote the earliest supplied comment of the cycle.
+            let mut seen = HashSet::new();
+            let mut idx = start;
+            while seen.insert(idx) {
+                idx = self.parents[idx].expect("unreachable comment has a parent");
+            }
+
+            let mut root = idx;
+            let mut member = self.parents[idx].expect("cycle member has a parent");
+            while member != idx {
+                root = root.min(member);
+                member = self.parents[member].expect("cycle member has a parent");
+            }
+
+            if let Some(parent) = self.parents[root].take() {
+                self.children[parent].retain(|&child| child != root);
+            }
+            self.roots.push(root);
+            stack.push(root);
+        }
+
+        self.roots.sort_unstable();
+    }
+
+    fn find_parent(&self, idx: usize) -> Option<usize> {
+        let comment = &self.comments[idx];
+
+        [comment.reply_id, comment.parent_id]
+            .into_iter()
+            .flatten()
+            .filter(|id| *id != comment.int_id)
+            .find_map(|id| self.index.get(&id).copied())
+    }
+
+    /// Total number of comments in the tree.
+    pub fn len(&self) -> usize {
+        self.comments.len()
+    }
+
+    /// Whether the tree contains no comments.
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+
+    /// Top-level comments, in their original order.
+    pub fn roots(&self) -> impl Iterator<Item = &Comment> {
+        self.roots.iter().map(|&idx| &self.comments[idx])
+    }
+
+    /// Look up a comment by its `int_id`.
+    pub fn get(&self, int_id: u64) -> Option<&Comment> {
+        self.index.get(&int_id).map(|&idx| &self.comments[idx])
+    }
+
+    /// Direct children of a comment. Empty if the comment is unknown or has no replies.
+    pub fn children(&self, int_id: u64) -> impl Iterator<Item = &Comment> {
+        self.index
+            .get(&int_id)
+            .map(|&idx| self.children[idx].as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|&idx| &self.comments[idx])
+    }
+
+    /// Parent of a comment, or `None` for roots and unknown comments.
+    pub fn parent(&self, int_id: u64) -> Option<&Comment> {
+        let idx = *self.index.get(&int_id)?;
+        self.parents[idx].map(|parent| &self.comments[parent])
+    }
+
+    /// Number of comments in the thread started by `root_int_id`, including the root.
+    ///
+    /// Returns `None` if the comment is not a root of this tree.
+    pub fn thread_size(&self, root_int_id: u64) -> Option<usize> {
+        let idx = *self.index.get(&root_int_id)?;
+        if self.parents[idx].is_some() {
+            return None;
+        }
+
+        Some(self.subtree_size(idx))
+    }
+
+    /// Thread sizes for every root, as `(root int_id, size)` pairs in root order.
+    pub fn thread_sizes(&self) -> Vec<(u64, usize)> {
+        self.roots
+            .iter()
+            .map(|&idx| (self.comments[idx].int_id, self.subtree_size(idx)))
+            .collect()
+    }
+
+    fn subtree_size(&self, idx: usize) -> usize {
+        let mut size = 0;
+        let mut stack = vec![idx];
+
+        while let Some(idx) = stack.pop() {
+            size += 1;
+            stack.extend(self.children[idx].iter().copied());
+        }
+
+        size
+    }
+
+    /// Iterate over all comments depth-first, yielding `(depth, comment)`.
+    ///
+    /// Roots have depth `0`, their direct replies depth `1`, and so on.
+    pub fn iter_depth_first(&self) -> DepthFirst<'_> {
+        DepthFirst {
+            tree: self,
+            stack: self.roots.iter().rev().map(|&idx| (0, idx)).collect(),
+        }
+    }
+
+    /// Consume the tree, returning comments in depth-first order.
+    pub fn into_comments(self) -> Vec<Comment> {
+        let order: Vec<usize> = self
+            .iter_depth_first()
+            .map(|(_, comment)| self.index[&comment.int_id])
+            .collect();
+
+        let mut slots: Vec<Option<Comment>> = self.comments.into_iter().map(Some).collect();
+        order
+            .into_iter()
+            .filter_map(|idx| slots[idx].take())
+            .collect()
+    }
+}
+
+/// Depth-first iterator over a [`CommentTree`].
+pub struct DepthFirst<'a> {
+    tree: &'a CommentTree,
+    stack: Vec<(usize, usize)>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (usize, &'a Comment);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, idx) = self.stack.pop()?;
+
+        self.stack.extend(
+            self.tree.children[idx]
+                .iter()
+                .rev()
+                .map(|&child| (depth + 1, child)),
+        );
+
+        Some((depth, &self.tree.comments[idx]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn comment(int_id: u64, parent_id: Option<u64>, reply_id: Option<u64>) -> serde_json::Value {
+        json!({
+            "id": format!("c{int_id}"),
+            "intId": int_id,
+            "post": {"id": "p"},
+            "author": {"id": 1, "name": "user", "hasAvatar": false, "avatarUrl": ""},
+            "createdAt": 1_700_000_000 + int_id,
+            "updatedAt": null,
+            "isDeleted": false,
+            "isBlocked": false,
+            "isUpdated": false,
+            "replyCount": 0,
+            "replies": null,
+            "data": [],
+            "reactions": {
+                "dislike": 0, "heart": 0, "fire": 0, "angry": 0,
+                "wonder": 0, "laught": 0, "sad": 0, "like": 0
+            },
+            "reactionCounters": [],
+            "parentId": parent_id,
+            "replyId": reply_id,
+            "replyToUser": null
+        })
+    }
+
+    fn parse(values: Vec<serde_json::Value>) -> Vec<Comment> {
+        values
+            .into_iter()
+            .map(|v| serde_json::from_value(v).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_links_replies_and_flattens_nested() {
+        let mut root = comment(1, None, None);
+        root["replyCount"] = json!(2);
+        root["replies"] = json!({
+            "data": [comment(2, Some(1), None), comment(3, Some(1), Some(2))],
+            "extra": {"isFirst": true, "isLast": true}
+        });
+
+        let tree = CommentTree::from_comments(parse(vec![root, comment(4, None, None)]));
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.roots().map(|c| c.int_id).collect::<Vec<_>>(), [1, 4]);
+        assert!(tree.get(1).unwrap().replies.is_none());
+        assert_eq!(tree.parent(3).unwrap().int_id, 2);
+        assert_eq!(tree.children(1).map(|c| c.int_id).collect::<Vec<_>>(), [2]);
+        assert_eq!(tree.thread_sizes(), vec![(1, 3), (4, 1)]);
+        assert_eq!(tree.thread_size(2), None);
+    }
+
+    #[test]
+    fn test_depth_first_order() {
+        let tree = CommentTree::from_comments(parse(vec![
+            comment(1, None, None),
+            comment(2, None, None),
+            comment(10, Some(1), None),
+            comment(11, Some(1), Some(10)),
+            comment(12, Some(1), None),
+            comment(20, Some(2), None),
+        ]));
+
+        let visited: Vec<(usize, u64)> = tree
+            .iter_depth_first()
+            .map(|(depth, c)| (depth, c.int_id))
+            .collect();
+
+        assert_eq!(
+            visited,
+            vec![(0, 1), (1, 10), (2, 11), (1, 12), (0, 2), (1, 20)]
+        );
+
+        let ids: Vec<u64> = tree.into_comments().iter().map(|c| c.int_id).collect();
+        assert_eq!(ids, vec![1, 10, 11, 12, 2, 20]);
+    }
+
+    #[test]
+    fn test_missing_parent_becomes_root_and_duplicates_dropped() {
+        let tree = CommentTree::from_comments(parse(vec![
+            comment(5, Some(99), Some(98)),
+            comment(6, Some(5), Some(99)),
+            comment(6, None, None),
+        ]));
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.roots().map(|c| c.int_id).collect::<Vec<_>>(), [5]);
+        assert_eq!(tree.parent(6).unwrap().int_id, 5);
+        assert_eq!(tree.children(404).count(), 0);
+    }
+
+    #[test]
+    fn test_cycle_members_promoted_to_root() {
+        let tree = CommentTree::from_comments(parse(vec![
+            comment(1, None, None),
+            comment(7, None, Some(9)),
+            comment(8, None, Some(7)),
+            comment(9, None, Some(8)),
+            comment(10, None, Some(8)),
+        ]));
+
+        assert_eq!(tree.roots().map(|c| c.int_id).collect::<Vec<_>>(), [1, 7]);
+        assert!(tree.parent(7).is_none());
+        assert_eq!(tree.parent(9).unwrap().int_id, 8);
+        assert_eq!(tree.thread_sizes(), vec![(1, 1), (7, 4)]);
+        assert_eq!(tree.iter_depth_first().count(), 5);
+    }
+}
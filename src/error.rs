@@ -62,6 +62,15 @@ pub enum ApiError {
     #[error("Failed to serialize JSON: {0}")]
     Serialization(#[from] serde_urlencoded::ser::Error),
 
+    #[error("Invalid post cursor: {0}")]
+    InvalidCursor(String),
+
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("Other error: {0}")]
     Other(String),
 }
@@ -0,0 +1,135 @@
+use serde::Serialize;
+
+use crate::{
+    error::{ApiError, ResultApi},
+    model::{Subscriber, SubscriptionStatus},
+};
+
+/// Column names of the subscriber CSV export, in order.
+pub const SUBSCRIBER_CSV_HEADER: [&str; 10] = [
+    "id",
+    "name",
+    "email",
+    "level_id",
+    "level_name",
+    "price",
+    "on_time",
+    "off_time",
+    "next_pay_time",
+    "status",
+];
+
+/// Flat view of a [`Subscriber`] used for CSV and JSON export.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SubscriberRecord {
+    /// User ID of the subscriber.
+    pub id: u64,
+    /// Display name.
+    pub name: String,
+    /// Email address, if shared.
+    pub email: Option<String>,
+    /// Subscription level ID, or `None` for followers.
+    pub level_id: Option<u64>,
+    /// Subscription level name, or `None` for followers.
+    pub level_name: Option<String>,
+    /// Price paid per period.
+    pub price: f64,
+    /// Start timestamp (Unix epoch).
+    pub on_time: i64,
+    /// End timestamp (Unix epoch), if unsubscribed.
+    pub off_time: Option<i64>,
+    /// Next payment timestamp (Unix epoch), if recurring.
+    pub next_pay_time: Option<i64>,
+    /// Current state of the subscription.
+    pub status: SubscriptionStatus,
+}
+
+impl From<&Subscriber> for SubscriberRecord {
+    fn from(subscriber: &Subscriber) -> Self {
+        Self {
+            id: subscriber.id,
+            name: subscriber.name.clone(),
+            email: subscriber.email.clone(),
+            level_id: subscriber.level.as_ref().map(|level| level.id),
+            level_name: subscriber.level.as_ref().map(|level| level.name.clone()),
+            price: subscriber.price,
+            on_time: subscriber.on_time,
+            off_time: subscriber.off_time,
+            next_pay_time: subscriber.next_pay_time,
+            status: subscriber.status(),
+        }
+    }
+}
+
+impl SubscriberRecord {
+    fn csv_fields(&self) -> [String; 10] {
+        fn opt<T: ToString>(value: &Option<T>) -> String {
+            value.as_ref().map(T::to_string).unwrap_or_default()
+        }
+
+        [
+            self.id.to_string(),
+            self.name.clone(),
+            opt(&self.email),
+            opt(&self.level_id),
+            opt(&self.level_name),
+            self.price.to_string(),
+            self.on_time.to_string(),
+            opt(&self.off_time),
+            opt(&self.next_pay_time),
+            self.status.as_str().to_string(),
+        ]
+    }
+}
+
+/// Export subscribers as CSV, with a header row and `\n` line endings.
+///
+/// Fields containing commas, quotes or line breaks are quoted per RFC 4180.
+/// Missing values are written as empty fields.
+pub fn subscribers_to_csv(subscribers: &[Subscriber]) -> String {
+    let mut out = SUBSCRIBER_CSV_HEADER.join(",");
+    out.push('\n');
+
+    for subscriber in subscribers {
+        let fields = SubscriberRecord::from(subscriber).csv_fields();
+        let escaped: Vec<String> = fields.iter().map(|field| escape_csv(field)).collect();
+        out.push_str(&escaped.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Export subscribers as a pretty-printed JSON array of [`SubscriberRecord`].
+///
+/// # Errors
+///
+/// - `ApiError::JsonParseDetailed` if serialization fails.
+pub fn subscribers_to_json(subscribers: &[Subscriber]) -> ResultApi<String> {
+    let records: Vec<SubscriberRecord> = subscribers.iter().map(SubscriberRecord::from).collect();
+
+    serde_json::to_string_pretty(&records).map_err(|e| ApiError::JsonParseDetailed {
+        error: e.to_string(),
+    })
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_csv() {
+        assert_eq!(escape_csv("plain"), "plain");
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv("two\nlines"), "\"two\nlines\"");
+    }
+}
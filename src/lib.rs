@@ -12,6 +12,10 @@
 //! - **Strongly typed API models** (`model`).
 //! - **Unified error handling** (`error`).
 //! - **Content extraction utilities** (`media_content`, `traits`).
+//! - **Comment threading** (`comment_tree`).
+//! - **Target completion polling** (`target_watcher`).
+//! - **Local post publishing queue** (`scheduler`).
+//! - **Subscriber export** to CSV and JSON (`export`).
 //!
 //! ## Module Summary
 //! - [`api_client`] — Boosty API HTTP client built on top of `reqwest`.
@@ -20,10 +24,18 @@
 //! - [`error`] — Error definitions covering network, parsing, and domain errors.
 //! - [`media_content`] — Defines [`ContentItem`] and helpers for extracting typed content.
 //! - [`traits`] — Common traits for entities that expose content, title, or availability.
+//! - [`comment_tree`] — [`comment_tree::CommentTree`] for assembling threaded comments.
+//! - [`export`] — CSV and JSON export of blog subscribers.
+//! - [`scheduler`] — [`scheduler::PostScheduler`] for publishing drafts at configured times.
+//! - [`target_watcher`] — [`target_watcher::TargetWatcher`] for detecting completed targets between polls.
 pub mod api_client;
 mod auth_provider;
+pub mod comment_tree;
 pub mod error;
+pub mod export;
 mod helper;
 pub mod media_content;
 pub mod model;
+pub mod scheduler;
+pub mod target_watcher;
 pub mod traits;
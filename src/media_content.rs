@@ -1,4 +1,4 @@
-use crate::model::{MediaData, PlayerUrl};
+use crate::model::{MediaData, PlayerUrl, PollOption};
 
 /// Represents a single content item extracted from a `Post` or `Comment`.
 #[derive(Debug, Clone)]
@@ -54,6 +54,15 @@ pub enum ContentItem {
         style: String,
         items: Vec<Vec<ContentItem>>,
     },
+    /// Poll with its question, options with vote counts and flags.
+    Poll {
+        id: u64,
+        title: String,
+        options: Vec<PollOption>,
+        total_votes: u64,
+        is_multiple: bool,
+        is_closed: bool,
+    },
     /// Fallback for unknown or unsupported media type.
     Unknown,
 }
@@ -80,6 +89,7 @@ pub fn extract_content(data: &[MediaData]) -> Vec<ContentItem> {
 /// - `Link` → `ContentItem::Link { explicit, content, url }`
 /// - `File` → `ContentItem::File { url, title, size }`
 /// - `List` → `ContentItem::List { style, items }`
+/// - `Poll` → `ContentItem::Poll { id, title, options, total_votes, is_multiple, is_closed }`
 /// - Other/Unknown → `ContentItem::Unknown`
 fn extract_media(media: &MediaData, out: &mut Vec<ContentItem>) {
     match media {
@@ -152,6 +162,14 @@ fn extract_media(media: &MediaData, out: &mut Vec<ContentItem>) {
                 items,
             });
         }
+        MediaData::Poll(poll) => out.push(ContentItem::Poll {
+            id: poll.id,
+            title: poll.title.clone(),
+            options: poll.options.clone(),
+            total_votes: poll.total_votes,
+            is_multiple: poll.is_multiple,
+            is_closed: poll.is_closed,
+        }),
         MediaData::Unknown => out.push(ContentItem::Unknown),
     }
 }
@@ -434,6 +452,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_poll() {
+        let poll = PollData {
+            id: 77,
+            title: "Next stream?".into(),
+            is_multiple: false,
+            is_closed: true,
+            options: vec![
+                PollOption {
+                    id: 1,
+                    text: "Friday".into(),
+                    votes: 3,
+                },
+                PollOption {
+                    id: 2,
+                    text: "Sunday".into(),
+                    votes: 1,
+                },
+            ],
+            total_votes: 4,
+            answer: vec![],
+            finish_time: None,
+        };
+        let post = dummy_post(vec![MediaData::Poll(poll)], true);
+        let content = post.extract_content();
+
+        assert!(
+            matches!(content[0], ContentItem::Poll { id, ref title, ref options, total_votes, is_multiple, is_closed }
+        if id == 77 && title == "Next stream?" && options.len() == 2 && options[0].votes == 3
+        && total_votes == 4 && !is_multiple && is_closed)
+        );
+    }
+
     #[test]
     fn test_extract_unknown() {
         let post = dummy_post(vec![MediaData::Unknown], true);
@@ -1,39 +1,93 @@
+mod blacklist;
+mod blog;
 mod bundle;
 mod comment;
 mod common;
+mod dialog;
+mod donation;
+mod feed;
+mod notification;
+mod poll;
 mod post;
+mod post_cursor;
+mod post_settings;
 mod reaction;
 mod showcase;
+mod stats;
+mod subscriber;
 mod subscription;
 mod subscription_level;
 mod tag;
 mod target;
+mod upload;
 mod user;
 
+pub use blacklist::{BlacklistResponse, BlockedUser};
+
+pub use blog::{
+    Blog, BlogCard, BlogCardsResponse, BlogCategoriesResponse, BlogCategory, BlogCount, CurrentUser,
+};
+
 pub use bundle::{
     Bundle, BundleExtra, BundleItem, BundleItemsData, BundleItemsResponse, BundleQuery,
-    BundlesResponse,
+    BundlesResponse, NewBundle,
 };
 
 pub use common::{ContentCounter, CurrencyPrices, Thumbnail};
 
+pub use dialog::{Dialog, DialogsExtra, DialogsResponse, Message, MessagesExtra, MessagesResponse};
+
+pub use donation::{
+    Currency, Donation, DonationForm, DonationOutcome, DonationRequest, DonationTarget,
+    PreparedDonation,
+};
+
+pub use feed::{ContentKind, FeedFilter};
+
+pub use notification::{Notification, NotificationKind, NotificationsExtra, NotificationsResponse};
+
 pub use post::{
-    AudioData, Comments, Count, Donators, ExtraFlag, FileData, Flags, ImageData, LinkData,
-    MediaData, OkVideoData, PlayerUrl, Post, PostsResponse, SmileData, TextData, VideoData,
+    AdvertiserInfo, AudioData, Comments, Count, Donator, Donators, ExtraFlag, FileData, Flags,
+    ImageData, LinkData, MediaData, OkVideoData, PlayerUrl, Post, PostsResponse, SmileData,
+    TextData, VideoData,
 };
 
-pub use comment::{Comment, CommentBlock, CommentsResponse};
+pub use poll::{PollData, PollOption};
+
+pub use post_cursor::PostCursor;
+
+pub use post_settings::{PostAccess, PostSettings};
+
+pub use comment::{Author, Comment, CommentBlock, CommentsResponse, MediaBlock, PostRef};
 
 pub use user::User;
 
-pub use reaction::{ReactionCounter, Reactions};
+pub use reaction::{ReactionCounter, ReactionKind, Reactions};
 
 pub use tag::{SearchTag, SearchTagsData, SearchTagsFullResponse, Tag, TagsResponse};
 
-pub use target::{NewTarget, Target, TargetResponse, TargetType, UpdateTarget};
+pub use target::{NewTarget, Target, TargetProgress, TargetResponse, TargetType, UpdateTarget};
 
-pub use subscription_level::{SubscriptionLevel, SubscriptionLevelResponse};
+pub use subscription_level::{
+    Access, Discount, NewPromo, NewSubscriptionLevel, Promo, PromosResponse, SubscriptionLevel,
+    SubscriptionLevelResponse,
+};
+
+pub use stats::{
+    IncomeEntry, IncomeResponse, IncomeSource, IncomeSummary, PostViews, PostViewsResponse,
+    SubscriberStatsPoint, SubscriberStatsResponse, TimeBucket,
+};
 
-pub use subscription::{Subscription, SubscriptionsResponse};
+pub use subscriber::{Subscriber, SubscribersResponse};
 
-pub use showcase::{ShowcaseData, ShowcaseItem, ShowcaseResponse};
+pub use subscription::{
+    BlogFlags, BlogInfo, BlogOwner, Subscription, SubscriptionLevelInfo, SubscriptionStatus,
+    SubscriptionsResponse,
+};
+
+pub use showcase::{ShowcaseData, ShowcaseItem, ShowcaseItemType, ShowcaseResponse};
+
+pub use upload::{
+    MAX_AUDIO_SIZE, MAX_FILE_SIZE, MAX_IMAGE_SIZE, MediaUpload, UploadKind, UploadProgress,
+    UploadSource, UploadedAudio, UploadedFile, UploadedImage, mime_from_file_name,
+};
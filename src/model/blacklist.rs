@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+use crate::model::Author;
+
+/// Page of users blocked in a blog.
+#[derive(Deserialize, Debug)]
+pub struct BlacklistResponse {
+    /// Blocked users on this page.
+    pub data: Vec<BlockedUser>,
+    /// Total number of blocked users.
+    pub total: u64,
+    /// Page size used by the server.
+    pub limit: u64,
+    /// Offset of this page.
+    pub offset: u64,
+}
+
+/// User blocked from commenting and messaging in a blog.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedUser {
+    /// The blocked user.
+    pub user: Author,
+    /// When the user was blocked (Unix epoch seconds).
+    pub blocked_at: i64,
+}
@@ -0,0 +1,121 @@
+use serde::Deserialize;
+
+use crate::model::subscription::{BlogFlags, BlogOwner, SubscriptionLevelInfo};
+
+/// Blog metadata returned by the blog info endpoint.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Blog {
+    /// Blog ID.
+    pub id: u64,
+    /// URL of the blog.
+    pub blog_url: String,
+    /// Title of the blog.
+    pub title: String,
+    /// Cover image URL.
+    pub cover_url: String,
+    /// Raw text description of the blog, if set.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether the blog contains adult content.
+    pub has_adult_content: bool,
+    /// Whether the current user is subscribed to the blog.
+    #[serde(default)]
+    pub is_subscribed: bool,
+    /// Blog owner information.
+    pub owner: BlogOwner,
+    /// Feature flags for the blog.
+    pub flags: BlogFlags,
+    /// Public counters of the blog.
+    pub count: BlogCount,
+    /// Subscription level of the current user in this blog, if subscribed.
+    #[serde(default)]
+    pub subscription: Option<SubscriptionLevelInfo>,
+}
+
+/// Public counters of a blog.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BlogCount {
+    /// Number of published posts.
+    pub posts: u64,
+    /// Number of subscribers, including followers.
+    pub subscribers: u64,
+    /// Number of likes on blog posts.
+    pub likes: u64,
+}
+
+/// Profile of the authenticated user.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentUser {
+    /// Unique user identifier.
+    pub id: u64,
+    /// User's display name.
+    pub name: String,
+    /// Whether the user has set an avatar.
+    pub has_avatar: bool,
+    /// URL to the user's avatar image.
+    pub avatar_url: String,
+    /// URL of the user's own blog, if the user is a blogger.
+    #[serde(default)]
+    pub blog_url: Option<String>,
+    /// Email address, if available to the current session.
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// Page of blog cards returned by blog search and discovery.
+#[derive(Deserialize, Debug)]
+pub struct BlogCardsResponse {
+    /// Blogs on this page.
+    pub data: Vec<BlogCard>,
+    /// Total number of matching blogs.
+    pub total: u64,
+    /// Page size used by the server.
+    pub limit: u64,
+    /// Offset of this page.
+    pub offset: u64,
+}
+
+/// Short blog description used in search results and discovery listings.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogCard {
+    /// URL of the blog.
+    pub blog_url: String,
+    /// Title of the blog.
+    pub title: String,
+    /// Cover image URL.
+    pub cover_url: String,
+    /// Blog owner information.
+    pub owner: BlogOwner,
+    /// Whether the blog contains adult content.
+    pub has_adult_content: bool,
+    /// Short description, if set.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Public counters of the blog.
+    #[serde(default)]
+    pub count: BlogCount,
+}
+
+/// API response containing blog categories.
+#[derive(Deserialize, Debug)]
+pub struct BlogCategoriesResponse {
+    /// Available categories.
+    pub data: Vec<BlogCategory>,
+}
+
+/// Category used to browse blogs.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogCategory {
+    /// Category ID.
+    pub id: u64,
+    /// Display title.
+    pub title: String,
+    /// Number of blogs in the category.
+    #[serde(default)]
+    pub blogs_count: u64,
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::{ApiError, ResultApi};
 use crate::model::{ContentCounter, CurrencyPrices, Post, ReactionCounter, Thumbnail};
 
 /// Query parameters for bundle requests.
@@ -130,3 +131,39 @@ pub struct BundleItem {
     /// Bundles this post belongs to.
     pub bundles: Vec<Bundle>,
 }
+
+/// Request form data for creating or updating a bundle.
+#[derive(Serialize, Debug, Clone)]
+pub struct NewBundle {
+    /// Bundle title.
+    pub title: String,
+    /// Bundle description.
+    pub description: String,
+    /// Price in the default currency.
+    pub price: f64,
+    /// Whether the bundle is hidden.
+    pub hidden: bool,
+    /// Sorting order for posts in the bundle, as in `Bundle.sorting`.
+    pub sorting: String,
+}
+
+impl NewBundle {
+    /// Check that the bundle can be sent to the API.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the title is blank or the price is negative or not finite.
+    pub fn validate(&self) -> ResultApi<()> {
+        if self.title.trim().is_empty() {
+            return Err(ApiError::InvalidInput("bundle title is empty".into()));
+        }
+        if !self.price.is_finite() || self.price < 0.0 {
+            return Err(ApiError::InvalidInput(format!(
+                "invalid bundle price: {}",
+                self.price
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -96,7 +96,7 @@ impl HasContent for Comment {
 }
 
 /// Comment block.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum CommentBlock {
     /// Text block.
@@ -105,9 +105,18 @@ pub enum CommentBlock {
     /// Smile block.
     #[serde(rename = "smile")]
     Smile(SmileBlock),
+    /// Uploaded image.
+    #[serde(rename = "image")]
+    Image(MediaBlock),
+    /// Uploaded audio file.
+    #[serde(rename = "audio_file")]
+    Audio(MediaBlock),
+    /// Uploaded file.
+    #[serde(rename = "file")]
+    File(MediaBlock),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TextBlock {
     pub content: String,
@@ -115,12 +124,19 @@ pub struct TextBlock {
     pub modificator: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SmileBlock {
     pub name: String,
 }
 
+/// Reference to previously uploaded media.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaBlock {
+    pub id: String,
+}
+
 impl CommentBlock {
     pub fn text(text: &str) -> Self {
         CommentBlock::Text(TextBlock {
@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+use crate::{
+    media_content::{self, ContentItem},
+    model::{Author, MediaData},
+    traits::HasContent,
+};
+
+/// API response containing a page of dialogs.
+#[derive(Deserialize, Debug)]
+pub struct DialogsResponse {
+    /// Dialogs, most recently active first.
+    pub data: Vec<Dialog>,
+    /// Pagination info.
+    pub extra: DialogsExtra,
+}
+
+/// Pagination info for dialogs.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogsExtra {
+    /// Whether this is the last page.
+    pub is_last: bool,
+    /// Offset of the next page.
+    #[serde(default)]
+    pub offset: Option<u64>,
+}
+
+/// A conversation with another user.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Dialog {
+    /// Dialog ID.
+    pub id: u64,
+    /// The other participant.
+    pub chatmate: Author,
+    /// Number of unread messages.
+    pub unread_count: u32,
+    /// Most recent message, if any.
+    pub last_message: Option<Message>,
+    /// Whether notifications for the dialog are muted.
+    #[serde(default)]
+    pub is_muted: bool,
+}
+
+/// API response containing a page of messages.
+#[derive(Deserialize, Debug)]
+pub struct MessagesResponse {
+    /// Messages, newest first.
+    pub data: Vec<Message>,
+    /// Pagination info.
+    pub extra: MessagesExtra,
+}
+
+/// Pagination info for messages.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MessagesExtra {
+    /// Whether the page contains the newest message.
+    pub is_first: bool,
+    /// Whether the page contains the oldest message.
+    pub is_last: bool,
+}
+
+/// A single message in a dialog.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    /// Message ID.
+    pub id: u64,
+    /// ID of the user who sent the message.
+    pub author_id: u64,
+    /// Creation timestamp (unix epoch).
+    pub created_at: i64,
+    /// Whether the recipient has read the message.
+    pub is_read: bool,
+    /// Message content blocks.
+    pub data: Vec<MediaData>,
+}
+
+impl HasContent for Message {
+    /// Extracts media items from message into a vector of `ContentItem`.
+    ///
+    /// # Returns
+    ///
+    /// Vector of `ContentItem` items.
+    fn extract_content(&self) -> Vec<ContentItem> {
+        media_content::extract_content(&self.data)
+    }
+}
@@ -0,0 +1,182 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ApiError, ResultApi};
+use crate::model::BlogFlags;
+
+/// Currency of a donation.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    /// Russian Ruble.
+    Rub,
+    /// US Dollar.
+    Usd,
+    /// Euro.
+    Eur,
+}
+
+/// What a donation is made to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DonationTarget {
+    /// The blog itself.
+    Blog,
+    /// A post, by post ID.
+    Post(String),
+    /// A blog target (goal), by target ID.
+    Target(u64),
+}
+
+/// Donation to a blog, post or target.
+#[derive(Debug, Clone)]
+pub struct DonationRequest {
+    /// Blog receiving the donation.
+    pub blog_name: String,
+    /// Amount to donate, with at most two decimal places.
+    pub amount: Decimal,
+    /// Currency of the amount.
+    pub currency: Currency,
+    /// Optional message shown to the blog owner.
+    pub message: Option<String>,
+    /// What the donation is made to.
+    pub target: DonationTarget,
+}
+
+/// Form data sent to the donation endpoint.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct DonationForm {
+    /// Amount to donate.
+    pub amount: Decimal,
+    /// Currency of the amount.
+    pub currency: Currency,
+    /// Message shown to the blog owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Target (goal) ID, for donations to a target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<u64>,
+}
+
+/// A donation request ready to be sent: endpoint path and form data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedDonation {
+    /// Relative API path under `/v1/`.
+    pub path: String,
+    /// Form data of the request.
+    pub form: DonationForm,
+}
+
+/// Donation created by the API.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Donation {
+    /// Donation ID.
+    pub id: u64,
+    /// Donated amount.
+    pub amount: Decimal,
+    /// Payment page to complete the donation, if payment is required.
+    #[serde(default)]
+    pub payment_url: Option<String>,
+}
+
+/// Result of `ApiClient::donate`.
+#[derive(Debug)]
+pub enum DonationOutcome {
+    /// The donation was sent.
+    Sent(Donation),
+    /// Dry run: the request was validated and built but not sent.
+    DryRun(PreparedDonation),
+}
+
+impl DonationRequest {
+    /// Create a donation to the blog itself, without a message.
+    pub fn new(blog_name: impl Into<String>, amount: Decimal, currency: Currency) -> Self {
+        Self {
+            blog_name: blog_name.into(),
+            amount,
+            currency,
+            message: None,
+            target: DonationTarget::Blog,
+        }
+    }
+
+    /// Attach a message for the blog owner.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Donate to a post instead of the blog.
+    pub fn for_post(mut self, post_id: impl Into<String>) -> Self {
+        self.target = DonationTarget::Post(post_id.into());
+        self
+    }
+
+    /// Donate to a blog target (goal) instead of the blog.
+    pub fn for_target(mut self, target_id: u64) -> Self {
+        self.target = DonationTarget::Target(target_id);
+        self
+    }
+
+    /// Check the request against the receiving blog's flags.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the amount is not positive or has more than two decimal places,
+    ///   the message is blank or not accepted by the blog, or the blog has no targets for a target
+    ///   donation.
+    pub fn validate(&self, flags: &BlogFlags) -> ResultApi<()> {
+        if self.amount <= Decimal::ZERO {
+            return Err(ApiError::InvalidInput(format!(
+                "donation amount must be positive, got {}",
+                self.amount
+            )));
+        }
+        if self.amount.normalize().scale() > 2 {
+            return Err(ApiError::InvalidInput(format!(
+                "donation amount has more than two decimal places: {}",
+                self.amount
+            )));
+        }
+
+        if let Some(message) = &self.message {
+            if message.trim().is_empty() {
+                return Err(ApiError::InvalidInput("donation message is empty".into()));
+            }
+            if !flags.accept_donation_messages {
+                return Err(ApiError::InvalidInput(
+                    "blog does not accept donation messages".into(),
+                ));
+            }
+        }
+
+        match self.target {
+            DonationTarget::Target(_) if !flags.has_targets => {
+                Err(ApiError::InvalidInput("blog has no targets".into()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Build the endpoint path and form data without validating.
+    pub fn prepare(&self) -> PreparedDonation {
+        let blog_name = &self.blog_name;
+        let (path, target_id) = match &self.target {
+            DonationTarget::Blog => (format!("blog/{blog_name}/donation/"), None),
+            DonationTarget::Post(post_id) => {
+                (format!("blog/{blog_name}/post/{post_id}/donation/"), None)
+            }
+            DonationTarget::Target(id) => (format!("blog/{blog_name}/donation/"), Some(*id)),
+        };
+
+        PreparedDonation {
+            path,
+            form: DonationForm {
+                amount: self.amount,
+                currency: self.currency,
+                message: self.message.clone(),
+                target_id,
+            },
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::model::Post;
+
+/// Kind of media a post contains, as reported by `Post.content_counters`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    /// Uploaded or OK.ru video.
+    Video,
+    /// Audio file.
+    Audio,
+    /// Downloadable file.
+    File,
+    /// Image.
+    Image,
+}
+
+impl ContentKind {
+    /// Content counter type names belonging to this kind.
+    fn counter_types(self) -> &'static [&'static str] {
+        match self {
+            ContentKind::Video => &["video", "ok_video"],
+            ContentKind::Audio => &["audio_file"],
+            ContentKind::File => &["file"],
+            ContentKind::Image => &["image"],
+        }
+    }
+}
+
+/// Client-side filter for feed posts.
+///
+/// An empty filter matches every post. Content kinds are alternatives: a post
+/// matches if it contains at least one of them. The publish time range is
+/// inclusive on both ends.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedFilter {
+    /// Keep only posts the current user has access to.
+    pub only_accessible: bool,
+    /// Keep only posts containing any of these content kinds.
+    pub content_kinds: Vec<ContentKind>,
+    /// Earliest publish time (unix epoch).
+    pub published_from: Option<i64>,
+    /// Latest publish time (unix epoch).
+    pub published_to: Option<i64>,
+}
+
+impl FeedFilter {
+    /// Create an empty filter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep only posts with `has_access`.
+    pub fn only_accessible(mut self) -> Self {
+        self.only_accessible = true;
+        self
+    }
+
+    /// Keep only posts containing `kind` (in addition to kinds already added).
+    pub fn with_content(mut self, kind: ContentKind) -> Self {
+        if !self.content_kinds.contains(&kind) {
+            self.content_kinds.push(kind);
+        }
+        self
+    }
+
+    /// Keep only posts published at or after `from`.
+    pub fn published_from(mut self, from: i64) -> Self {
+        self.published_from = Some(from);
+        self
+    }
+
+    /// Keep only posts published at or before `to`.
+    pub fn published_to(mut self, to: i64) -> Self {
+        self.published_to = Some(to);
+        self
+    }
+
+    /// Whether `post` passes the filter.
+    pub fn matches(&self, post: &Post) -> bool {
+        if self.only_accessible && !post.has_access {
+            return false;
+        }
+
+        if self
+            .published_from
+            .is_some_and(|from| post.publish_time < from)
+            || self.published_to.is_some_and(|to| post.publish_time > to)
+        {
+            return false;
+        }
+
+        self.content_kinds.is_empty()
+            || self.content_kinds.iter().any(|kind| {
+                post.content_counters
+                    .iter()
+                    .any(|c| c.count > 0 && kind.counter_types().contains(&c.type_.as_str()))
+            })
+    }
+
+    /// Whether a post published at `publish_time` is older than the filter window.
+    ///
+    /// The feed is newest-first, so once this is true no later page can match.
+    pub fn is_before_window(&self, publish_time: i64) -> bool {
+        self.published_from.is_some_and(|from| publish_time < from)
+    }
+}
@@ -0,0 +1,73 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::model::{Author, Comment, PostRef, ReactionKind};
+
+/// API response containing a page of notifications.
+#[derive(Deserialize, Debug)]
+pub struct NotificationsResponse {
+    /// Notifications, newest first.
+    pub data: Vec<Notification>,
+    /// Pagination info.
+    pub extra: NotificationsExtra,
+}
+
+/// Pagination info for notifications.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationsExtra {
+    /// Whether this is the last page.
+    pub is_last: bool,
+    /// Offset of the next page.
+    #[serde(default)]
+    pub offset: Option<u64>,
+}
+
+/// Kind of event a notification reports.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// New comment on the user's post.
+    Comment,
+    /// Reply to the user's comment.
+    Reply,
+    /// New subscription or follow.
+    Subscription,
+    /// Donation received.
+    Donation,
+    /// Reaction on the user's post or comment.
+    Reaction,
+    /// Kind not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A single notification.
+///
+/// Which optional fields are present depends on `kind`: comments and replies
+/// embed the `comment`, reactions carry `reaction`, donations carry `amount`.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    /// Notification ID.
+    pub id: u64,
+    /// Kind of event.
+    #[serde(rename = "type")]
+    pub kind: NotificationKind,
+    /// Creation timestamp (unix epoch).
+    pub created_at: i64,
+    /// Whether the notification has been read.
+    pub is_read: bool,
+    /// User who triggered the event.
+    pub author: Option<Author>,
+    /// Blog the event relates to.
+    pub blog_url: Option<String>,
+    /// Post the event relates to.
+    pub post: Option<PostRef>,
+    /// Embedded comment for comment and reply notifications.
+    pub comment: Option<Comment>,
+    /// Reaction for reaction notifications.
+    pub reaction: Option<ReactionKind>,
+    /// Amount for donation notifications.
+    pub amount: Option<Decimal>,
+}
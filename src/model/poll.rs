@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use crate::error::{ApiError, ResultApi};
+
+/// Poll embedded in a post.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PollData {
+    /// Poll ID.
+    pub id: u64,
+    /// Poll question.
+    pub title: String,
+    /// Whether several options may be chosen.
+    #[serde(default)]
+    pub is_multiple: bool,
+    /// Whether voting has ended.
+    #[serde(default)]
+    pub is_closed: bool,
+    /// Answer options.
+    #[serde(default)]
+    pub options: Vec<PollOption>,
+    /// Total number of votes.
+    #[serde(default)]
+    pub total_votes: u64,
+    /// Options chosen by the current user, empty if not voted.
+    #[serde(default)]
+    pub answer: Vec<u64>,
+    /// Time the poll closes (Unix epoch seconds), if set.
+    #[serde(default)]
+    pub finish_time: Option<i64>,
+}
+
+/// Answer option of a poll.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PollOption {
+    /// Option ID.
+    pub id: u64,
+    /// Option text.
+    pub text: String,
+    /// Number of votes for this option.
+    #[serde(default)]
+    pub votes: u64,
+}
+
+impl PollData {
+    /// Whether the current user has voted.
+    pub fn has_voted(&self) -> bool {
+        !self.answer.is_empty()
+    }
+
+    /// Share of votes for an option, in percent. `None` if the option does not exist.
+    pub fn percent(&self, option_id: u64) -> Option<f64> {
+        let option = self.options.iter().find(|o| o.id == option_id)?;
+        if self.total_votes == 0 {
+            return Some(0.0);
+        }
+
+        Some(option.votes as f64 * 100.0 / self.total_votes as f64)
+    }
+
+    /// Check a vote before sending.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the poll is closed, no option is given, an option does not
+    ///   belong to the poll or is repeated, or several options are given for a single-choice poll.
+    pub fn validate_vote(&self, option_ids: &[u64]) -> ResultApi<()> {
+        if self.is_closed {
+            return Err(ApiError::InvalidInput(format!(
+                "poll {} is closed",
+                self.id
+            )));
+        }
+        if option_ids.is_empty() {
+            return Err(ApiError::InvalidInput("no poll option chosen".into()));
+        }
+        if !self.is_multiple && option_ids.len() > 1 {
+            return Err(ApiError::InvalidInput(format!(
+                "poll {} allows only one option",
+                self.id
+            )));
+        }
+
+        let mut seen = HashSet::new();
+        for id in option_ids {
+            if !self.options.iter().any(|o| o.id == *id) {
+                return Err(ApiError::InvalidInput(format!(
+                    "option {id} does not belong to poll {}",
+                    self.id
+                )));
+            }
+            if !seen.insert(id) {
+                return Err(ApiError::InvalidInput(format!(
+                    "option {id} is chosen twice"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
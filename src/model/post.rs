@@ -1,5 +1,6 @@
+use crate::error::ResultApi;
 use crate::media_content;
-use crate::model::{ContentCounter, CurrencyPrices};
+use crate::model::{Author, Comment, ContentCounter, CurrencyPrices, PollData, PostCursor};
 use crate::traits::{HasContent, HasTitle, IsAvailable};
 use crate::{
     media_content::ContentItem,
@@ -17,6 +18,23 @@ pub struct PostsResponse {
     pub extra: Extra,
 }
 
+impl PostsResponse {
+    /// Cursor to request the page following this one.
+    ///
+    /// Returns `None` if this is the last page or the API sent no offset.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidCursor` if `extra.offset` is not in `sortOrder:int_id` form.
+    pub fn next_cursor(&self) -> ResultApi<Option<PostCursor>> {
+        if self.extra.is_last {
+            return Ok(None);
+        }
+
+        PostCursor::parse_offset(&self.extra.offset)
+    }
+}
+
 /// Extra (offset, is_last).
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -86,8 +104,8 @@ pub struct Post {
     pub updated_at: i64,
     /// Signed query string for accessing protected content.
     pub signed_query: String,
-    /// Optional advertiser metadata (unstructured).
-    pub advertiser_info: Option<serde_json::Value>,
+    /// Optional advertiser metadata (ad labeling).
+    pub advertiser_info: Option<AdvertiserInfo>,
     /// Price details in various currencies.
     pub currency_prices: CurrencyPrices,
     /// Should the post be shown in the showcase.
@@ -277,8 +295,33 @@ pub struct PlayerUrl {
 pub struct Donators {
     /// Additional metadata flags.
     pub extra: ExtraFlag,
-    /// Raw data array for donators (untyped JSON).
-    pub data: Vec<serde_json::Value>,
+    /// Donators of the post.
+    pub data: Vec<Donator>,
+}
+
+/// Single donator of a post.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Donator {
+    /// User who donated.
+    pub user: Author,
+    /// Donated amount.
+    pub amount: Decimal,
+}
+
+/// Advertiser metadata attached to sponsored posts.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AdvertiserInfo {
+    /// Advertiser display name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Advertiser taxpayer identification number.
+    #[serde(default)]
+    pub inn: Option<String>,
+    /// Advertisement registration token.
+    #[serde(default)]
+    pub erid: Option<String>,
 }
 
 /// Extra flags container.
@@ -295,8 +338,8 @@ pub struct ExtraFlag {
 pub struct Comments {
     /// Additional metadata flags.
     pub extra: ExtraFlag,
-    /// Raw data array for comments (untyped JSON).
-    pub data: Vec<serde_json::Value>,
+    /// Preview comments embedded in the post.
+    pub data: Vec<Comment>,
 }
 
 /// Post counts summary.
@@ -349,6 +392,8 @@ pub enum MediaData {
     File(FileData),
     #[serde(rename = "list", rename_all = "camelCase")]
     List(ListData),
+    #[serde(rename = "poll", rename_all = "camelCase")]
+    Poll(PollData),
     #[serde(other)]
     Unknown,
 }
@@ -383,6 +428,13 @@ impl HasTitle for Post {
     }
 }
 
+impl Post {
+    /// Cursor pointing at this post in the blog's post list.
+    pub fn cursor(&self) -> PostCursor {
+        PostCursor::from(self)
+    }
+}
+
 impl HasContent for Post {
     /// Extracts media items from post into a vector of `ContentItem`.
     ///
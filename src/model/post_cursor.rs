@@ -0,0 +1,148 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{error::ApiError, model::Post};
+
+/// Position in a blog's post list.
+///
+/// Boosty pages posts newest-first and uses an offset of the form
+/// `"{sortOrder}:{int_id}"` (e.g. `"1762949608:9555337"`) to continue listing.
+/// A cursor points at the last post already received; passing it back
+/// to the API returns the posts that follow it.
+///
+/// Cursors are ordered by position in the list: a cursor that compares
+/// `Less` points to an older post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PostCursor {
+    /// Sort order of the post (usually the publish timestamp).
+    pub sort_order: i64,
+    /// Internal numeric ID of the post.
+    pub int_id: i64,
+}
+
+impl PostCursor {
+    /// Create a cursor from its raw components.
+    pub fn new(sort_order: i64, int_id: i64) -> Self {
+        Self { sort_order, int_id }
+    }
+
+    /// Parse an optional wire offset, treating an empty string as "no cursor".
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidCursor` if the offset is non-empty and malformed.
+    pub fn parse_offset(offset: &str) -> Result<Option<Self>, ApiError> {
+        if offset.is_empty() {
+            return Ok(None);
+        }
+
+        offset.parse().map(Some)
+    }
+}
+
+impl From<&Post> for PostCursor {
+    fn from(post: &Post) -> Self {
+        Self::new(post.sort_order, post.int_id)
+    }
+}
+
+impl fmt::Display for PostCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.sort_order, self.int_id)
+    }
+}
+
+impl FromStr for PostCursor {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ApiError::InvalidCursor(s.to_string());
+
+        let (sort_order, int_id) = s.split_once(':').ok_or_else(invalid)?;
+        let sort_order = sort_order.trim().parse().map_err(|_| invalid())?;
+        let int_id = int_id.trim().parse().map_err(|_| invalid())?;
+
+        Ok(Self::new(sort_order, int_id))
+    }
+}
+
+impl PartialOrd for PostCursor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PostCursor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_order
+            .cmp(&other.sort_order)
+            .then(self.int_id.cmp(&other.int_id))
+    }
+}
+
+impl Serialize for PostCursor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PostCursor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display_roundtrip() {
+        let cursor: PostCursor = "1762949608:9555337".parse().unwrap();
+        assert_eq!(cursor, PostCursor::new(1762949608, 9555337));
+        assert_eq!(cursor.to_string(), "1762949608:9555337");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            "1762949608".parse::<PostCursor>(),
+            Err(ApiError::InvalidCursor(_))
+        ));
+        assert!(matches!(
+            "abc:1".parse::<PostCursor>(),
+            Err(ApiError::InvalidCursor(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_offset_empty() {
+        assert_eq!(PostCursor::parse_offset("").unwrap(), None);
+        assert_eq!(
+            PostCursor::parse_offset("10:2").unwrap(),
+            Some(PostCursor::new(10, 2))
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        let older = PostCursor::new(100, 5);
+        let newer = PostCursor::new(200, 1);
+        let same_time = PostCursor::new(100, 6);
+
+        assert!(older < newer);
+        assert!(older < same_time);
+        assert_eq!(vec![older, newer].into_iter().max(), Some(newer));
+    }
+
+    #[test]
+    fn test_serde_as_string() {
+        let cursor = PostCursor::new(1, 2);
+        let json = serde_json::to_string(&cursor).unwrap();
+        assert_eq!(json, "\"1:2\"");
+        let back: PostCursor = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, cursor);
+    }
+}
@@ -0,0 +1,124 @@
+use serde::Serialize;
+
+use crate::error::{ApiError, ResultApi};
+
+/// Request body for changing display and interaction flags of an existing post.
+///
+/// Only fields that are set are sent; the rest keep their current values.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PostSettings {
+    /// Pin or unpin the post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_pinned: Option<bool>,
+    /// Show or hide the post in the showcase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_showcase_visible: Option<bool>,
+    /// Deny or allow comments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_comments_denied: Option<bool>,
+    /// Disable or enable reactions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions_disabled: Option<bool>,
+}
+
+impl PostSettings {
+    /// Create settings with no changes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pin (`true`) or unpin (`false`) the post.
+    pub fn pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = Some(is_pinned);
+        self
+    }
+
+    /// Show (`true`) or hide (`false`) the post in the showcase.
+    pub fn showcase_visible(mut self, is_visible: bool) -> Self {
+        self.is_showcase_visible = Some(is_visible);
+        self
+    }
+
+    /// Deny (`true`) or allow (`false`) comments.
+    pub fn comments_denied(mut self, is_denied: bool) -> Self {
+        self.is_comments_denied = Some(is_denied);
+        self
+    }
+
+    /// Disable (`true`) or enable (`false`) reactions.
+    pub fn reactions_disabled(mut self, is_disabled: bool) -> Self {
+        self.reactions_disabled = Some(is_disabled);
+        self
+    }
+
+    /// Validate the request before sending.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if no field is set.
+    pub fn validate(&self) -> ResultApi<()> {
+        if *self == Self::default() {
+            return Err(ApiError::InvalidInput(
+                "post settings must change at least one flag".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Who can access a post.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PostAccess {
+    /// Available to everyone, including followers.
+    Free,
+    /// Available to subscribers of the given subscription level and above.
+    Level(u64),
+    /// Available for a one-time purchase at the given price.
+    Price(f64),
+}
+
+/// Form body sent to change post access.
+#[derive(Serialize, Debug)]
+pub(crate) struct PostAccessForm {
+    pub subscription_level_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+}
+
+impl PostAccess {
+    /// Validate the access before sending.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the level ID is 0 (use [`PostAccess::Free`]) or the price
+    ///   is not a positive finite number.
+    pub fn validate(&self) -> ResultApi<()> {
+        match *self {
+            PostAccess::Level(0) => Err(ApiError::InvalidInput(
+                "subscription level id must not be 0".into(),
+            )),
+            PostAccess::Price(price) if !price.is_finite() || price <= 0.0 => Err(
+                ApiError::InvalidInput("price must be greater than 0".into()),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn to_form(self) -> PostAccessForm {
+        match self {
+            PostAccess::Free => PostAccessForm {
+                subscription_level_id: 0,
+                price: None,
+            },
+            PostAccess::Level(level_id) => PostAccessForm {
+                subscription_level_id: level_id,
+                price: None,
+            },
+            PostAccess::Price(price) => PostAccessForm {
+                subscription_level_id: 0,
+                price: Some(price),
+            },
+        }
+    }
+}
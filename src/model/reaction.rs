@@ -1,7 +1,12 @@
-use serde::Deserialize;
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Reactions summary.
-#[derive(Deserialize, Debug)]
+///
+/// Only the classic reaction kinds are reported here; the full set is
+/// available through `ReactionCounter` lists.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Reactions {
     pub dislike: u32,
@@ -15,10 +20,292 @@ pub struct Reactions {
 }
 
 /// Reaction counter.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReactionCounter {
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ReactionKind,
     pub count: u32,
 }
+
+/// Kind of reaction that can be put on a post or comment.
+///
+/// Serialized as the wire string used by Boosty (e.g. `"heart"`, `"call-me"`).
+/// Kinds not known to this crate are kept in `Unknown` with the raw value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReactionKind {
+    Like,
+    Dislike,
+    Heart,
+    Fire,
+    Angry,
+    Wonder,
+    /// Wire value is `"laught"`.
+    Laugh,
+    Sad,
+    Funny,
+    Check,
+    Thinking,
+    Clown,
+    Swearing,
+    Applause,
+    CallMe,
+    Cold,
+    Eggplant,
+    BlowingUp,
+    Scream,
+    Crown,
+    Diamond,
+    Melting,
+    Money,
+    Nausea,
+    Confetti,
+    Banana,
+    Peach,
+    Pizza,
+    Love,
+    Rocket,
+    Splash,
+    Lightning,
+    Cry,
+    FoldedHands,
+    /// Fallback for reaction kinds not known to this crate.
+    Unknown(String),
+}
+
+impl ReactionKind {
+    /// Reaction kinds tracked by the fixed-field `Reactions` summary.
+    pub const SUMMARY_KINDS: [ReactionKind; 8] = [
+        ReactionKind::Like,
+        ReactionKind::Dislike,
+        ReactionKind::Heart,
+        ReactionKind::Fire,
+        ReactionKind::Angry,
+        ReactionKind::Wonder,
+        ReactionKind::Laugh,
+        ReactionKind::Sad,
+    ];
+
+    /// Wire representation of the reaction kind.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReactionKind::Like => "like",
+            ReactionKind::Dislike => "dislike",
+            ReactionKind::Heart => "heart",
+            ReactionKind::Fire => "fire",
+            ReactionKind::Angry => "angry",
+            ReactionKind::Wonder => "wonder",
+            ReactionKind::Laugh => "laught",
+            ReactionKind::Sad => "sad",
+            ReactionKind::Funny => "funny",
+            ReactionKind::Check => "check",
+            ReactionKind::Thinking => "thinking",
+            ReactionKind::Clown => "clown",
+            ReactionKind::Swearing => "swearing",
+            ReactionKind::Applause => "applause",
+            ReactionKind::CallMe => "call-me",
+            ReactionKind::Cold => "cold",
+            ReactionKind::Eggplant => "eggplant",
+            ReactionKind::BlowingUp => "blowing-up",
+            ReactionKind::Scream => "scream",
+            ReactionKind::Crown => "crown",
+            ReactionKind::Diamond => "diamond",
+            ReactionKind::Melting => "melting",
+            ReactionKind::Money => "money",
+            ReactionKind::Nausea => "nausea",
+            ReactionKind::Confetti => "confetti",
+            ReactionKind::Banana => "banana",
+            ReactionKind::Peach => "peach",
+            ReactionKind::Pizza => "pizza",
+            ReactionKind::Love => "love",
+            ReactionKind::Rocket => "rocket",
+            ReactionKind::Splash => "splash",
+            ReactionKind::Lightning => "lightning",
+            ReactionKind::Cry => "cry",
+            ReactionKind::FoldedHands => "folded-hands",
+            ReactionKind::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl From<&str> for ReactionKind {
+    fn from(value: &str) -> Self {
+        match value {
+            "like" => ReactionKind::Like,
+            "dislike" => ReactionKind::Dislike,
+            "heart" => ReactionKind::Heart,
+            "fire" => ReactionKind::Fire,
+            "angry" => ReactionKind::Angry,
+            "wonder" => ReactionKind::Wonder,
+            "laught" => ReactionKind::Laugh,
+            "sad" => ReactionKind::Sad,
+            "funny" => ReactionKind::Funny,
+            "check" => ReactionKind::Check,
+            "thinking" => ReactionKind::Thinking,
+            "clown" => ReactionKind::Clown,
+            "swearing" => ReactionKind::Swearing,
+            "applause" => ReactionKind::Applause,
+            "call-me" => ReactionKind::CallMe,
+            "cold" => ReactionKind::Cold,
+            "eggplant" => ReactionKind::Eggplant,
+            "blowing-up" => ReactionKind::BlowingUp,
+            "scream" => ReactionKind::Scream,
+            "crown" => ReactionKind::Crown,
+            "diamond" => ReactionKind::Diamond,
+            "melting" => ReactionKind::Melting,
+            "money" => ReactionKind::Money,
+            "nausea" => ReactionKind::Nausea,
+            "confetti" => ReactionKind::Confetti,
+            "banana" => ReactionKind::Banana,
+            "peach" => ReactionKind::Peach,
+            "pizza" => ReactionKind::Pizza,
+            "love" => ReactionKind::Love,
+            "rocket" => ReactionKind::Rocket,
+            "splash" => ReactionKind::Splash,
+            "lightning" => ReactionKind::Lightning,
+            "cry" => ReactionKind::Cry,
+            "folded-hands" => ReactionKind::FoldedHands,
+            other => ReactionKind::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ReactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ReactionKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReactionKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Ok(ReactionKind::from(raw.as_str()))
+    }
+}
+
+impl Reactions {
+    /// Count for the given reaction kind.
+    ///
+    /// Returns `0` for kinds not tracked by the summary.
+    pub fn get(&self, kind: &ReactionKind) -> u32 {
+        match kind {
+            ReactionKind::Like => self.like,
+            ReactionKind::Dislike => self.dislike,
+            ReactionKind::Heart => self.heart,
+            ReactionKind::Fire => self.fire,
+            ReactionKind::Angry => self.angry,
+            ReactionKind::Wonder => self.wonder,
+            ReactionKind::Laugh => self.laught,
+            ReactionKind::Sad => self.sad,
+            _ => 0,
+        }
+    }
+
+    /// Total number of reactions in the summary.
+    pub fn total(&self) -> u32 {
+        ReactionKind::SUMMARY_KINDS
+            .iter()
+            .map(|kind| self.get(kind))
+            .sum()
+    }
+
+    /// Convert the summary into a list of counters, one per tracked kind.
+    pub fn to_counters(&self) -> Vec<ReactionCounter> {
+        ReactionKind::SUMMARY_KINDS
+            .iter()
+            .map(|kind| ReactionCounter {
+                count: self.get(kind),
+                type_: kind.clone(),
+            })
+            .collect()
+    }
+}
+
+impl From<&[ReactionCounter]> for Reactions {
+    /// Build a summary from counters, ignoring kinds it does not track.
+    fn from(counters: &[ReactionCounter]) -> Self {
+        let mut reactions = Reactions::default();
+
+        for counter in counters {
+            let slot = match counter.type_ {
+                ReactionKind::Like => &mut reactions.like,
+                ReactionKind::Dislike => &mut reactions.dislike,
+                ReactionKind::Heart => &mut reactions.heart,
+                ReactionKind::Fire => &mut reactions.fire,
+                ReactionKind::Angry => &mut reactions.angry,
+                ReactionKind::Wonder => &mut reactions.wonder,
+                ReactionKind::Laugh => &mut reactions.laught,
+                ReactionKind::Sad => &mut reactions.sad,
+                _ => continue,
+            };
+            *slot += counter.count;
+        }
+
+        reactions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_wire_roundtrip() {
+        let kinds: Vec<ReactionKind> =
+            serde_json::from_str(r#"["laught", "call-me", "heart", "brand-new"]"#).unwrap();
+
+        assert_eq!(
+            kinds,
+            vec![
+                ReactionKind::Laugh,
+                ReactionKind::CallMe,
+                ReactionKind::Heart,
+                ReactionKind::Unknown("brand-new".into()),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&kinds).unwrap(),
+            r#"["laught","call-me","heart","brand-new"]"#
+        );
+    }
+
+    #[test]
+    fn test_reactions_counters_conversion() {
+        let reactions = Reactions {
+            heart: 3,
+            laught: 2,
+            like: 1,
+            ..Default::default()
+        };
+
+        let counters = reactions.to_counters();
+        assert_eq!(counters.len(), 8);
+        assert_eq!(reactions.total(), 6);
+        assert_eq!(Reactions::from(counters.as_slice()), reactions);
+    }
+
+    #[test]
+    fn test_reactions_from_counters_ignores_untracked() {
+        let counters = vec![
+            ReactionCounter {
+                type_: ReactionKind::Fire,
+                count: 4,
+            },
+            ReactionCounter {
+                type_: ReactionKind::Rocket,
+                count: 10,
+            },
+        ];
+
+        let reactions = Reactions::from(counters.as_slice());
+        assert_eq!(reactions.fire, 4);
+        assert_eq!(reactions.get(&ReactionKind::Rocket), 0);
+        assert_eq!(reactions.total(), 4);
+    }
+}
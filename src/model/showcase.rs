@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::model::Post;
 
@@ -57,3 +57,27 @@ pub struct ShowcaseItem {
     /// Item position
     pub position: i64,
 }
+
+impl ShowcaseItem {
+    /// Typed view of `item_type`. Unrecognized values map to `ShowcaseItemType::Unknown`.
+    pub fn kind(&self) -> ShowcaseItemType {
+        match self.item_type.as_str() {
+            "post" => ShowcaseItemType::Post,
+            "bundle" => ShowcaseItemType::Bundle,
+            _ => ShowcaseItemType::Unknown,
+        }
+    }
+}
+
+/// Type of a showcase item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShowcaseItemType {
+    /// Post item
+    Post,
+    /// Bundle item
+    Bundle,
+    /// Fallback for item types not known to this crate
+    #[serde(other)]
+    Unknown,
+}
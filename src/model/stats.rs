@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// API response containing income records for a period.
+#[derive(Deserialize, Debug)]
+pub struct IncomeResponse {
+    /// Income records, one per payment.
+    pub data: Vec<IncomeEntry>,
+}
+
+/// A single income record.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeEntry {
+    /// Payment timestamp (Unix epoch).
+    pub time: i64,
+    /// Where the income came from.
+    pub source: IncomeSource,
+    /// Amount received, in the blog's default currency.
+    pub amount: Decimal,
+}
+
+/// Source of an income record.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IncomeSource {
+    /// Subscription payment.
+    Subscription,
+    /// Donation to the blog, a post or a target.
+    Donation,
+    /// Purchase of a single post.
+    Post,
+    /// Purchase of a bundle.
+    Bundle,
+    /// Fallback for sources not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// API response containing subscriber counts over time.
+#[derive(Deserialize, Debug)]
+pub struct SubscriberStatsResponse {
+    /// Data points in chronological order.
+    pub data: Vec<SubscriberStatsPoint>,
+}
+
+/// Subscriber counts at a point in time.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriberStatsPoint {
+    /// Timestamp of the data point (Unix epoch).
+    pub time: i64,
+    /// Number of paying subscribers.
+    pub subscribers: u64,
+    /// Number of free followers.
+    pub followers: u64,
+}
+
+/// API response containing view counts per post.
+#[derive(Deserialize, Debug)]
+pub struct PostViewsResponse {
+    /// View statistics, one entry per post.
+    pub data: Vec<PostViews>,
+}
+
+/// View statistics of a single post.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostViews {
+    /// Post ID.
+    pub post_id: String,
+    /// Post title, if any.
+    pub title: Option<String>,
+    /// Publish timestamp (Unix epoch).
+    pub published_at: i64,
+    /// Number of views in the requested period.
+    pub views: u64,
+}
+
+/// Length of a reporting period.
+///
+/// Buckets are aligned in UTC; weeks start on Monday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBucket {
+    Day,
+    Week,
+    Month,
+}
+
+impl TimeBucket {
+    /// Start of the bucket containing `timestamp` (Unix epoch seconds, UTC).
+    pub fn start_of(&self, timestamp: i64) -> i64 {
+        let days = timestamp.div_euclid(SECONDS_PER_DAY);
+
+        let start_day = match self {
+            TimeBucket::Day => days,
+            // 1970-01-01 was a Thursday, three days after Monday.
+            TimeBucket::Week => days - (days + 3).rem_euclid(7),
+            TimeBucket::Month => {
+                let (year, month, _) = civil_from_days(days);
+                days_from_civil(year, month, 1)
+            }
+        };
+
+        start_day * SECONDS_PER_DAY
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Income totals by source, summed with exact decimals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncomeSummary {
+    /// Income from subscriptions.
+    pub subscriptions: Decimal,
+    /// Income from donations.
+    pub donations: Decimal,
+    /// Income from single post purchases.
+    pub posts: Decimal,
+    /// Income from bundle purchases.
+    pub bundles: Decimal,
+    /// Income from sources not known to this crate.
+    pub other: Decimal,
+}
+
+impl IncomeSummary {
+    /// Sum income records by source.
+    pub fn from_entries(entries: &[IncomeEntry]) -> Self {
+        let mut summary = IncomeSummary::default();
+        for entry in entries {
+            summary.add(entry);
+        }
+        summary
+    }
+
+    /// Add a single income record.
+    pub fn add(&mut self, entry: &IncomeEntry) {
+        let slot = match entry.source {
+            IncomeSource::Subscription => &mut self.subscriptions,
+            IncomeSource::Donation => &mut self.donations,
+            IncomeSource::Post => &mut self.posts,
+            IncomeSource::Bundle => &mut self.bundles,
+            IncomeSource::Unknown => &mut self.other,
+        };
+        *slot += entry.amount;
+    }
+
+    /// Total income over all sources.
+    pub fn total(&self) -> Decimal {
+        self.subscriptions + self.donations + self.posts + self.bundles + self.other
+    }
+
+    /// Group income records into buckets and sum each one.
+    ///
+    /// Returns `(bucket start, summary)` pairs in chronological order; empty buckets are omitted.
+    pub fn bucketed(entries: &[IncomeEntry], bucket: TimeBucket) -> Vec<(i64, IncomeSummary)> {
+        let mut buckets: BTreeMap<i64, IncomeSummary> = BTreeMap::new();
+        for entry in entries {
+            buckets
+                .entry(bucket.start_of(entry.time))
+                .or_default()
+                .add(entry);
+        }
+        buckets.into_iter().collect()
+    }
+}
+
+/// Convert days since 1970-01-01 into a `(year, month, day)` civil date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Convert a civil date into days since 1970-01-01.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_roundtrip() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(days_from_civil(2024, 3, 1), 19_783);
+
+        for days in -1_000..100_000 {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn test_bucket_start() {
+        // 2024-02-29T15:30:00Z, a Thursday.
+        let ts = 1_709_220_600;
+
+        assert_eq!(TimeBucket::Day.start_of(ts), 1_709_164_800);
+        assert_eq!(TimeBucket::Week.start_of(ts), 1_708_905_600);
+        assert_eq!(TimeBucket::Month.start_of(ts), 1_706_745_600);
+        assert_eq!(TimeBucket::Week.start_of(0), -3 * SECONDS_PER_DAY);
+    }
+}
@@ -0,0 +1,64 @@
+use serde::Deserialize;
+
+use crate::model::{SubscriptionLevelInfo, SubscriptionStatus};
+
+/// API response containing a paginated list of a blog's subscribers.
+#[derive(Deserialize, Debug)]
+pub struct SubscribersResponse {
+    /// List of subscribers.
+    pub data: Vec<Subscriber>,
+    /// Total number of subscribers.
+    pub total: u64,
+    /// Number of items per page.
+    pub limit: u64,
+    /// Offset of the current page.
+    pub offset: u64,
+}
+
+/// A subscriber or follower of the owner's blog.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscriber {
+    /// User ID of the subscriber.
+    pub id: u64,
+    /// Display name.
+    pub name: String,
+    /// Whether the user has a custom avatar.
+    pub has_avatar: bool,
+    /// URL of the avatar.
+    pub avatar_url: String,
+    /// Email address, if shared with the blog owner.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Price paid per period (in base currency units); `0` for followers.
+    pub price: f64,
+    /// Start timestamp (Unix epoch).
+    pub on_time: i64,
+    /// Optional end timestamp (if unsubscribed).
+    pub off_time: Option<i64>,
+    /// Optional next payment timestamp (if recurring).
+    pub next_pay_time: Option<i64>,
+    /// Whether the subscription is paused.
+    pub is_pause: bool,
+    /// Whether the subscription is suspended.
+    pub is_suspended: bool,
+    /// Whether the subscription is archived.
+    #[serde(default)]
+    pub is_archived: bool,
+    /// Subscription level, or `None` for free followers.
+    pub level: Option<SubscriptionLevelInfo>,
+}
+
+impl Subscriber {
+    /// Current state of the subscription, with the same precedence as `Subscription::status`.
+    pub fn status(&self) -> SubscriptionStatus {
+        SubscriptionStatus::from_flags(self.is_archived, self.is_suspended, self.is_pause)
+    }
+
+    /// Whether this entry is a free follower rather than a subscriber of a level.
+    ///
+    /// Decided by `level` alone; `price` is not consulted, since a level may be free.
+    pub fn is_follower(&self) -> bool {
+        self.level.is_none()
+    }
+}
@@ -1,5 +1,5 @@
-use crate::model::{CurrencyPrices, subscription_level::Promo};
-use serde::Deserialize;
+use crate::model::{CurrencyPrices, MediaData, subscription_level::Promo};
+use serde::{Deserialize, Serialize};
 
 /// API response containing a paginated list of subscriptions.
 #[derive(Deserialize, Debug)]
@@ -60,6 +60,70 @@ pub struct Subscription {
     pub recommended_promo: Option<Promo>,
 }
 
+/// State of a subscription, derived from its flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionStatus {
+    /// Subscription is running.
+    Active,
+    /// Subscription is paused by the subscriber.
+    Paused,
+    /// Subscription is suspended, e.g. after a failed payment.
+    Suspended,
+    /// Subscription is archived.
+    Archived,
+}
+
+impl SubscriptionStatus {
+    /// Derive the status from subscription flags.
+    ///
+    /// When several flags are set, `Archived` wins over `Suspended`, which wins over `Paused`.
+    pub fn from_flags(is_archived: bool, is_suspended: bool, is_pause: bool) -> Self {
+        if is_archived {
+            SubscriptionStatus::Archived
+        } else if is_suspended {
+            SubscriptionStatus::Suspended
+        } else if is_pause {
+            SubscriptionStatus::Paused
+        } else {
+            SubscriptionStatus::Active
+        }
+    }
+
+    /// Lowercase name of the status, as used in exports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubscriptionStatus::Active => "active",
+            SubscriptionStatus::Paused => "paused",
+            SubscriptionStatus::Suspended => "suspended",
+            SubscriptionStatus::Archived => "archived",
+        }
+    }
+}
+
+impl Subscription {
+    /// Current state of the subscription.
+    ///
+    /// When several flags are set, `Archived` wins over `Suspended`, which wins over `Paused`.
+    pub fn status(&self) -> SubscriptionStatus {
+        SubscriptionStatus::from_flags(self.is_archived, self.is_suspended, self.is_pause)
+    }
+
+    /// Whether the subscription is active.
+    pub fn is_active(&self) -> bool {
+        self.status() == SubscriptionStatus::Active
+    }
+
+    /// Whether the next payment is due between `now` and `now + days` (Unix epoch seconds).
+    ///
+    /// Returns `false` if there is no scheduled payment.
+    pub fn next_payment_within(&self, days: u32, now: i64) -> bool {
+        let horizon = now.saturating_add(i64::from(days).saturating_mul(86_400));
+        self.next_pay_time
+            .is_some_and(|time| (now..=horizon).contains(&time))
+    }
+}
+
 /// Basic info about the associated subscription level.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -84,8 +148,8 @@ pub struct SubscriptionLevelInfo {
     pub owner_id: u64,
     /// Timestamp of creation (Unix epoch).
     pub created_at: i64,
-    /// List of content blocks (JSON, e.g., images/text).
-    pub data: Vec<serde_json::Value>,
+    /// Level description content blocks (text, images, etc.).
+    pub data: Vec<MediaData>,
 }
 
 /// Blog information associated with the subscription.
@@ -1,6 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::model::CurrencyPrices;
+use crate::error::{ApiError, ResultApi};
+use crate::model::{CommentBlock, CurrencyPrices};
 
 /// API response containing a list of subscription levels.
 #[derive(Deserialize, Debug)]
@@ -67,7 +68,7 @@ pub struct Promo {
 }
 
 /// Access rights granted by a promo campaign.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Access {
     /// Whether holders of other subscription levels can access.
@@ -100,6 +101,101 @@ pub struct Discount {
     pub currency_prices: CurrencyPrices,
 }
 
+/// API response containing the promos of a subscription level.
+#[derive(Deserialize, Debug)]
+pub struct PromosResponse {
+    /// Array of promo items.
+    pub data: Vec<Promo>,
+}
+
+/// Request body for creating a discount promo on a subscription level.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewPromo {
+    /// Discount percentage, from 1 to 99.
+    pub discount_percent: u32,
+    /// Start timestamp of the promo (Unix epoch).
+    pub start_time: i64,
+    /// End timestamp of the promo, or `None` if it does not expire.
+    pub end_time: Option<i64>,
+    /// Maximum number of activations, or `None` if unlimited.
+    pub max_activation: Option<u64>,
+    /// Audience the promo is offered to.
+    pub access: Access,
+    /// Optional human-readable description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl NewPromo {
+    /// Create an unlimited, non-expiring promo for the given audience.
+    pub fn new(discount_percent: u32, start_time: i64, access: Access) -> Self {
+        Self {
+            discount_percent,
+            start_time,
+            end_time: None,
+            max_activation: None,
+            access,
+            description: None,
+        }
+    }
+
+    /// Set the end timestamp.
+    pub fn with_end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Limit the number of activations.
+    pub fn with_max_activation(mut self, max_activation: u64) -> Self {
+        self.max_activation = Some(max_activation);
+        self
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Check that the promo can be sent to the API.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the discount is outside `1..=99`, the end time is not after
+    ///   the start time, the activation limit is zero, or no audience is selected.
+    pub fn validate(&self) -> ResultApi<()> {
+        if !(1..=99).contains(&self.discount_percent) {
+            return Err(ApiError::InvalidInput(format!(
+                "discount percent must be between 1 and 99, got {}",
+                self.discount_percent
+            )));
+        }
+        if self.end_time.is_some_and(|end| end <= self.start_time) {
+            return Err(ApiError::InvalidInput(
+                "promo end time must be after start time".into(),
+            ));
+        }
+        if self.max_activation == Some(0) {
+            return Err(ApiError::InvalidInput(
+                "max activation must be greater than zero".into(),
+            ));
+        }
+        let Access {
+            access_other_level_subscriber,
+            new_subscriber,
+            old_paid_subscriber,
+        } = self.access;
+        if !(access_other_level_subscriber || new_subscriber || old_paid_subscriber) {
+            return Err(ApiError::InvalidInput(
+                "promo access must include at least one audience".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// Represents a content block (text or image).
 #[derive(Deserialize, Debug)]
 #[serde(tag = "type")]
@@ -177,3 +273,67 @@ pub struct TelegramApp {
     /// Whether Telegram is configured.
     pub is_configured: bool,
 }
+
+/// Request data for creating or updating a subscription level.
+#[derive(Debug, Clone)]
+pub struct NewSubscriptionLevel {
+    /// Name of the subscription level.
+    pub name: String,
+    /// Monthly price in the blog's default currency.
+    pub price: f64,
+    /// Description content blocks, encoded like comment content.
+    pub description: Vec<CommentBlock>,
+    /// Maximum number of subscribers, or `None` for an unlimited level.
+    pub subscribers_limit: Option<u32>,
+}
+
+impl NewSubscriptionLevel {
+    /// Create an unlimited level with no description.
+    pub fn new(name: impl Into<String>, price: f64) -> Self {
+        Self {
+            name: name.into(),
+            price,
+            description: Vec::new(),
+            subscribers_limit: None,
+        }
+    }
+
+    /// Set description blocks.
+    pub fn with_description(mut self, description: Vec<CommentBlock>) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Limit the number of subscribers.
+    pub fn with_subscribers_limit(mut self, limit: u32) -> Self {
+        self.subscribers_limit = Some(limit);
+        self
+    }
+
+    /// Check that the level can be sent to the API.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the name is blank, the price is negative or not finite,
+    ///   or the subscribers limit is zero.
+    pub fn validate(&self) -> ResultApi<()> {
+        if self.name.trim().is_empty() {
+            return Err(ApiError::InvalidInput(
+                "subscription level name is empty".into(),
+            ));
+        }
+        if !self.price.is_finite() || self.price < 0.0 {
+            return Err(ApiError::InvalidInput(format!(
+                "invalid subscription level price: {}",
+                self.price
+            )));
+        }
+        if self.subscribers_limit == Some(0) {
+            return Err(ApiError::InvalidInput(
+                "subscribers limit must be greater than zero".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
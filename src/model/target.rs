@@ -56,9 +56,55 @@ pub struct UpdateTarget {
     pub target_sum: f64,
 }
 
-#[derive(Deserialize, Debug)]
+/// Kind of a target: collected money or number of subscribers.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum TargetType {
     Money,
     Subscribers,
 }
+
+/// Progress of a target towards its sum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetProgress {
+    /// Completion in percent, clamped to `0.0..=100.0`.
+    pub percent: f64,
+    /// Amount still missing, never negative.
+    ///
+    /// For [`TargetType::Subscribers`] this is rounded up to whole subscribers.
+    pub remaining: f64,
+    /// Whether the current sum has reached the target sum.
+    pub is_completed: bool,
+}
+
+impl Target {
+    /// Compute progress towards the target sum.
+    ///
+    /// A target with a non-positive `target_sum` is considered complete.
+    pub fn progress(&self) -> TargetProgress {
+        let is_completed = self.current_sum >= self.target_sum;
+
+        let percent = if self.target_sum <= 0.0 {
+            100.0
+        } else {
+            (self.current_sum / self.target_sum * 100.0).clamp(0.0, 100.0)
+        };
+
+        let remaining = (self.target_sum - self.current_sum).max(0.0);
+        let remaining = match self.type_ {
+            TargetType::Money => remaining,
+            TargetType::Subscribers => remaining.ceil(),
+        };
+
+        TargetProgress {
+            percent,
+            remaining,
+            is_completed,
+        }
+    }
+
+    /// Whether the current sum has reached the target sum.
+    pub fn is_completed(&self) -> bool {
+        self.progress().is_completed
+    }
+}
@@ -0,0 +1,339 @@
+use std::{fmt, path::PathBuf, sync::Arc};
+
+use serde::Deserialize;
+use tokio::io::AsyncRead;
+
+use crate::{
+    error::{ApiError, ResultApi},
+    model::{CommentBlock, MediaBlock},
+};
+
+/// Maximum size of an uploaded image, in bytes (20 MiB).
+pub const MAX_IMAGE_SIZE: u64 = 20 * 1024 * 1024;
+/// Maximum size of an uploaded audio file, in bytes (500 MiB).
+pub const MAX_AUDIO_SIZE: u64 = 500 * 1024 * 1024;
+/// Maximum size of an uploaded arbitrary file, in bytes (2 GiB).
+pub const MAX_FILE_SIZE: u64 = 2 * 1024 * 1024 * 1024;
+
+/// Callback receiving `(bytes_sent, total_bytes)` while an upload is streamed.
+pub type UploadProgress = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
+/// Kind of media being uploaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadKind {
+    /// Image embedded in content.
+    Image,
+    /// Audio track.
+    Audio,
+    /// Arbitrary downloadable file.
+    File,
+}
+
+impl UploadKind {
+    /// Maximum accepted size in bytes.
+    pub fn max_size(self) -> u64 {
+        match self {
+            UploadKind::Image => MAX_IMAGE_SIZE,
+            UploadKind::Audio => MAX_AUDIO_SIZE,
+            UploadKind::File => MAX_FILE_SIZE,
+        }
+    }
+
+    /// Whether `mime_type` is accepted for this kind. Files accept any type.
+    pub fn accepts(self, mime_type: &str) -> bool {
+        match self {
+            UploadKind::Image => matches!(
+                mime_type,
+                "image/jpeg" | "image/png" | "image/gif" | "image/webp"
+            ),
+            UploadKind::Audio => matches!(
+                mime_type,
+                "audio/mpeg" | "audio/mp4" | "audio/ogg" | "audio/wav" | "audio/flac"
+            ),
+            UploadKind::File => true,
+        }
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            UploadKind::Image => "image",
+            UploadKind::Audio => "audio",
+            UploadKind::File => "file",
+        }
+    }
+}
+
+/// Guess a MIME type from the extension of `file_name`.
+///
+/// Unknown extensions map to `application/octet-stream`.
+pub fn mime_from_file_name(file_name: &str) -> &'static str {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "ogg" | "oga" => "audio/ogg",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "txt" => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Where upload content is read from.
+pub enum UploadSource {
+    /// File on disk; size is read from its metadata.
+    Path(PathBuf),
+    /// In-memory bytes.
+    Bytes(Vec<u8>),
+    /// Async stream with a known length.
+    Reader {
+        reader: Box<dyn AsyncRead + Send + Unpin>,
+        length: u64,
+    },
+}
+
+impl fmt::Debug for UploadSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UploadSource::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            UploadSource::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            UploadSource::Reader { length, .. } => {
+                f.debug_struct("Reader").field("length", length).finish()
+            }
+        }
+    }
+}
+
+/// Media to upload.
+///
+/// # Example
+///
+/// ```rust
+/// use boosty_api::model::MediaUpload;
+///
+/// let upload = MediaUpload::from_bytes("cover.png", vec![0x89, 0x50, 0x4e, 0x47])
+///     .with_progress(|sent, total| println!("{sent}/{total}"));
+/// assert_eq!(upload.mime_type(), "image/png");
+/// ```
+pub struct MediaUpload {
+    /// File name sent with the upload.
+    pub file_name: String,
+    /// Content source.
+    pub source: UploadSource,
+    /// MIME type override; guessed from `file_name` when `None`.
+    pub mime_type: Option<String>,
+    /// Progress callback.
+    pub progress: Option<UploadProgress>,
+}
+
+impl fmt::Debug for MediaUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MediaUpload")
+            .field("file_name", &self.file_name)
+            .field("source", &self.source)
+            .field("mime_type", &self.mime_type)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl MediaUpload {
+    /// Upload a file from disk; the file name is taken from the path.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Self::new(file_name, UploadSource::Path(path))
+    }
+
+    /// Upload in-memory bytes.
+    pub fn from_bytes(file_name: impl Into<String>, bytes: Vec<u8>) -> Self {
+        Self::new(file_name.into(), UploadSource::Bytes(bytes))
+    }
+
+    /// Upload from an async reader producing exactly `length` bytes.
+    pub fn from_reader(
+        file_name: impl Into<String>,
+        reader: impl AsyncRead + Send + Unpin + 'static,
+        length: u64,
+    ) -> Self {
+        Self::new(
+            file_name.into(),
+            UploadSource::Reader {
+                reader: Box::new(reader),
+                length,
+            },
+        )
+    }
+
+    fn new(file_name: String, source: UploadSource) -> Self {
+        Self {
+            file_name,
+            source,
+            mime_type: None,
+            progress: None,
+        }
+    }
+
+    /// Set the MIME type instead of guessing it from the file name.
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Report progress as `(bytes_sent, total_bytes)` while the body is streamed.
+    pub fn with_progress(mut self, progress: impl Fn(u64, u64) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// MIME type that will be sent.
+    pub fn mime_type(&self) -> &str {
+        self.mime_type
+            .as_deref()
+            .unwrap_or_else(|| mime_from_file_name(&self.file_name))
+    }
+
+    /// Check size and MIME type against the limits of `kind`.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::InvalidInput` if the file name is empty, the content is empty or larger than
+    ///   `kind.max_size()`, or the MIME type is not accepted for `kind`.
+    pub fn validate(&self, kind: UploadKind, length: u64) -> ResultApi<()> {
+        if self.file_name.trim().is_empty() {
+            return Err(ApiError::InvalidInput("file name must not be empty".into()));
+        }
+        if length == 0 {
+            return Err(ApiError::InvalidInput(format!(
+                "{} is empty",
+                self.file_name
+            )));
+        }
+        if length > kind.max_size() {
+            return Err(ApiError::InvalidInput(format!(
+                "{} is {length} bytes, {} uploads are limited to {} bytes",
+                self.file_name,
+                kind.as_str(),
+                kind.max_size()
+            )));
+        }
+
+        let mime_type = self.mime_type();
+        if !kind.accepts(mime_type) {
+            return Err(ApiError::InvalidInput(format!(
+                "{mime_type} is not a supported {} type",
+                kind.as_str()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Uploaded image.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UploadedImage {
+    /// Media ID to reference in content blocks.
+    pub id: String,
+    /// URL of the image.
+    pub url: String,
+    /// Width in pixels.
+    pub width: Option<u32>,
+    /// Height in pixels.
+    pub height: Option<u32>,
+}
+
+/// Uploaded audio file.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UploadedAudio {
+    /// Media ID to reference in content blocks.
+    pub id: String,
+    /// URL of the audio file.
+    pub url: String,
+    /// Track title.
+    #[serde(default)]
+    pub title: String,
+    /// Size in bytes.
+    pub size: u64,
+}
+
+/// Uploaded arbitrary file.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UploadedFile {
+    /// Media ID to reference in content blocks.
+    pub id: String,
+    /// URL of the file.
+    pub url: String,
+    /// File title.
+    #[serde(default)]
+    pub title: String,
+    /// Size in bytes.
+    pub size: u64,
+}
+
+impl From<&UploadedImage> for CommentBlock {
+    fn from(image: &UploadedImage) -> Self {
+        CommentBlock::Image(MediaBlock {
+            id: image.id.clone(),
+        })
+    }
+}
+
+impl From<&UploadedAudio> for CommentBlock {
+    fn from(audio: &UploadedAudio) -> Self {
+        CommentBlock::Audio(MediaBlock {
+            id: audio.id.clone(),
+        })
+    }
+}
+
+impl From<&UploadedFile> for CommentBlock {
+    fn from(file: &UploadedFile) -> Self {
+        CommentBlock::File(MediaBlock {
+            id: file.id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_size_and_mime() {
+        let image = MediaUpload::from_bytes("a.png", vec![1]);
+        assert!(image.validate(UploadKind::Image, 1).is_ok());
+        assert!(image.validate(UploadKind::Image, 0).is_err());
+        assert!(
+            image
+                .validate(UploadKind::Image, MAX_IMAGE_SIZE + 1)
+                .is_err()
+        );
+        assert!(image.validate(UploadKind::Audio, 1).is_err());
+        assert!(image.validate(UploadKind::File, 1).is_ok());
+
+        let audio = MediaUpload::from_bytes("track", vec![1]).with_mime_type("audio/mpeg");
+        assert!(audio.validate(UploadKind::Audio, 1).is_ok());
+    }
+
+    #[test]
+    fn test_mime_from_file_name() {
+        assert_eq!(mime_from_file_name("Photo.JPG"), "image/jpeg");
+        assert_eq!(mime_from_file_name("song.mp3"), "audio/mpeg");
+        assert_eq!(mime_from_file_name("archive"), "application/octet-stream");
+    }
+}
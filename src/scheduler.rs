@@ -0,0 +1,164 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{api_client::ApiClient, error::ResultApi, model::Post};
+
+/// Draft queued for publication at a given time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledDraft {
+    /// Blog the draft belongs to.
+    pub blog_name: String,
+    /// Draft post ID.
+    pub post_id: String,
+    /// Publication time (Unix epoch seconds).
+    pub publish_at: i64,
+}
+
+impl ScheduledDraft {
+    /// Create a queue entry.
+    pub fn new(blog_name: impl Into<String>, post_id: impl Into<String>, publish_at: i64) -> Self {
+        Self {
+            blog_name: blog_name.into(),
+            post_id: post_id.into(),
+            publish_at,
+        }
+    }
+}
+
+/// Result of publishing one queued draft.
+#[derive(Debug)]
+pub struct PublishResult {
+    /// The queue entry that was processed.
+    pub draft: ScheduledDraft,
+    /// Published post, or the error returned by [`ApiClient::publish_post`].
+    pub result: ResultApi<Post>,
+}
+
+/// Local queue that publishes prepared drafts at configured times.
+///
+/// Unlike [`ApiClient::schedule_post`], publication is driven by this process:
+/// drafts stay unpublished on Boosty until [`PostScheduler::publish_due`] or
+/// [`PostScheduler::run`] publishes them. Drafts due at the same time are
+/// published in insertion order. A failed publication is reported and not retried.
+#[derive(Debug, Default)]
+pub struct PostScheduler {
+    queue: Vec<ScheduledDraft>,
+}
+
+impl PostScheduler {
+    /// Create an empty scheduler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a draft.
+    pub fn push(&mut self, draft: ScheduledDraft) {
+        let index = self
+            .queue
+            .partition_point(|queued| queued.publish_at <= draft.publish_at);
+        self.queue.insert(index, draft);
+    }
+
+    /// Remove a queued draft. Returns `true` if it was queued.
+    pub fn cancel(&mut self, blog_name: &str, post_id: &str) -> bool {
+        let len = self.queue.len();
+        self.queue
+            .retain(|d| !(d.blog_name == blog_name && d.post_id == post_id));
+        self.queue.len() != len
+    }
+
+    /// Queued drafts, earliest first.
+    pub fn pending(&self) -> &[ScheduledDraft] {
+        &self.queue
+    }
+
+    /// Number of queued drafts.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Publication time of the earliest queued draft.
+    pub fn next_publish_at(&self) -> Option<i64> {
+        self.queue.first().map(|d| d.publish_at)
+    }
+
+    /// Remove and return drafts due at or before `now` (Unix epoch seconds).
+    pub fn take_due(&mut self, now: i64) -> Vec<ScheduledDraft> {
+        let due = self.queue.partition_point(|d| d.publish_at <= now);
+        self.queue.drain(..due).collect()
+    }
+
+    /// Publish every draft due at or before `now`.
+    pub async fn publish_due(&mut self, client: &ApiClient, now: i64) -> Vec<PublishResult> {
+        let mut results = Vec::new();
+
+        for draft in self.take_due(now) {
+            let result = client.publish_post(&draft.blog_name, &draft.post_id).await;
+            results.push(PublishResult { draft, result });
+        }
+
+        results
+    }
+
+    /// Sleep until each queued draft is due and publish it, until the queue is empty.
+    ///
+    /// `on_result` is called after each publication attempt.
+    pub async fn run(&mut self, client: &ApiClient, mut on_result: impl FnMut(&PublishResult)) {
+        while let Some(publish_at) = self.next_publish_at() {
+            let wait = publish_at.saturating_sub(unix_now()).max(0) as u64;
+            if wait > 0 {
+                tokio::time::sleep(Duration::from_secs(wait)).await;
+            }
+
+            for result in self.publish_due(client, unix_now()).await {
+                on_result(&result);
+            }
+        }
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(drafts: &[ScheduledDraft]) -> Vec<&str> {
+        drafts.iter().map(|d| d.post_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_queue_ordered_by_time_then_insertion() {
+        let mut scheduler = PostScheduler::new();
+        scheduler.push(ScheduledDraft::new("blog", "c", 300));
+        scheduler.push(ScheduledDraft::new("blog", "a", 100));
+        scheduler.push(ScheduledDraft::new("blog", "b", 300));
+
+        assert_eq!(ids(scheduler.pending()), vec!["a", "c", "b"]);
+        assert_eq!(scheduler.next_publish_at(), Some(100));
+    }
+
+    #[test]
+    fn test_take_due_and_cancel() {
+        let mut scheduler = PostScheduler::new();
+        scheduler.push(ScheduledDraft::new("blog", "a", 100));
+        scheduler.push(ScheduledDraft::new("blog", "b", 200));
+        scheduler.push(ScheduledDraft::new("blog", "c", 300));
+
+        assert!(scheduler.cancel("blog", "b"));
+        assert!(!scheduler.cancel("blog", "b"));
+
+        assert_eq!(ids(&scheduler.take_due(99)), Vec::<&str>::new());
+        assert_eq!(ids(&scheduler.take_due(300)), vec!["a", "c"]);
+        assert!(scheduler.is_empty());
+    }
+}
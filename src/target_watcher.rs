@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::{api_client::ApiClient, error::ResultApi, model::Target};
+
+/// Polls a blog's targets and reports the ones completed since the previous poll.
+///
+/// The first poll only records the current state, so targets that were already
+/// complete are not reported. New targets that appear complete on a later poll are
+/// reported once.
+#[derive(Debug)]
+pub struct TargetWatcher {
+    blog_name: String,
+    completed: HashMap<u64, bool>,
+    initialized: bool,
+}
+
+impl TargetWatcher {
+    /// Create a watcher for the given blog.
+    pub fn new(blog_name: impl Into<String>) -> Self {
+        Self {
+            blog_name: blog_name.into(),
+            completed: HashMap::new(),
+            initialized: false,
+        }
+    }
+
+    /// Blog whose targets are watched.
+    pub fn blog_name(&self) -> &str {
+        &self.blog_name
+    }
+
+    /// Fetch targets and return those that became complete since the last poll.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by [`ApiClient::get_blog_targets`]. The watcher state is
+    /// left unchanged on error.
+    pub async fn poll(&mut self, client: &ApiClient) -> ResultApi<Vec<Target>> {
+        let targets = client.get_blog_targets(&self.blog_name).await?;
+        Ok(self.observe(targets.data))
+    }
+
+    /// Record a snapshot of targets and return those that became complete.
+    ///
+    /// Useful when targets are fetched elsewhere. Targets missing from the
+    /// snapshot are forgotten.
+    pub fn observe(&mut self, targets: Vec<Target>) -> Vec<Target> {
+        let first = !self.initialized;
+        self.initialized = true;
+
+        let mut completed = HashMap::with_capacity(targets.len());
+        let mut newly_completed = Vec::new();
+
+        for target in targets {
+            let is_completed = target.is_completed();
+            let was_completed = self.completed.get(&target.id).copied();
+            completed.insert(target.id, is_completed);
+
+            if is_completed && !first && was_completed != Some(true) {
+                newly_completed.push(target);
+            }
+        }
+
+        self.completed = completed;
+        newly_completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn target(id: u64, current_sum: f64) -> Target {
+        serde_json::from_value(json!({
+            "description": "goal",
+            "bloggerId": 1,
+            "priority": 1,
+            "createdAt": 1690000000,
+            "id": id,
+            "targetSum": 100.0,
+            "currentSum": current_sum,
+            "finishTime": null,
+            "bloggerUrl": "blog",
+            "type": "money"
+        }))
+        .unwrap()
+    }
+
+    fn ids(targets: &[Target]) -> Vec<u64> {
+        targets.iter().map(|t| t.id).collect()
+    }
+
+    #[test]
+    fn test_first_observation_is_baseline() {
+        let mut watcher = TargetWatcher::new("blog");
+
+        assert!(
+            watcher
+                .observe(vec![target(1, 100.0), target(2, 10.0)])
+                .is_empty()
+        );
+        assert!(
+            watcher
+                .observe(vec![target(1, 100.0), target(2, 10.0)])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_reports_completion_once() {
+        let mut watcher = TargetWatcher::new("blog");
+        watcher.observe(vec![target(1, 50.0), target(2, 10.0)]);
+
+        let done = watcher.observe(vec![target(1, 100.0), target(2, 20.0), target(3, 150.0)]);
+        assert_eq!(ids(&done), vec![1, 3]);
+
+        let done = watcher.observe(vec![target(1, 120.0), target(2, 100.0), target(3, 150.0)]);
+        assert_eq!(ids(&done), vec![2]);
+    }
+}
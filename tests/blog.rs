@@ -0,0 +1,245 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{api_client::ApiClient, error::ApiError};
+use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::json;
+
+use crate::helpers::{api_path, setup};
+
+#[tokio::test]
+async fn test_get_current_user_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+    client.set_bearer_token("tok123").await.unwrap();
+
+    let path = api_path("user/current");
+    let raw = fs::read_to_string("tests/fixtures/api_response_current_user.json").unwrap();
+
+    server
+        .mock("GET", path.as_str())
+        .match_header("authorization", "Bearer tok123")
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(raw)
+        .create_async()
+        .await;
+
+    let user = client.get_current_user().await.unwrap();
+    assert_eq!(user.id, 222);
+    assert_eq!(user.name, "TestUser2");
+    assert_eq!(user.blog_url.as_deref(), Some("testblog"));
+}
+
+#[tokio::test]
+async fn test_get_current_user_without_blog() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("user/current");
+    let body = json!({
+        "id": 7,
+        "name": "Reader",
+        "hasAvatar": false,
+        "avatarUrl": ""
+    });
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(body.to_string())
+        .create_async()
+        .await;
+
+    let user = client.get_current_user().await.unwrap();
+    assert_eq!(user.blog_url, None);
+    assert_eq!(user.email, None);
+}
+
+#[tokio::test]
+async fn test_get_current_user_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("user/current");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client.get_current_user().await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_get_blog_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "jove";
+    let path = api_path(&format!("blog/{blog}"));
+    let raw = fs::read_to_string("tests/fixtures/api_response_blog.json").unwrap();
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(raw)
+        .create_async()
+        .await;
+
+    let info = client.get_blog(blog).await.unwrap();
+    assert_eq!(info.blog_url, "jove");
+    assert_eq!(info.owner.name, "Jove");
+    assert!(info.flags.has_subscription_levels);
+    assert_eq!(info.count.posts, 1520);
+    assert_eq!(info.count.subscribers, 98765);
+    assert_eq!(info.subscription.unwrap().name, "Follower");
+}
+
+#[tokio::test]
+async fn test_get_blog_not_found() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/missing");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let res = client.get_blog("missing").await;
+    assert!(matches!(res, Err(ApiError::HttpStatus { status, .. }) if status == 404));
+}
+
+#[tokio::test]
+async fn test_get_blog_invalid_json() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/jove");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body("{invalid json")
+        .create_async()
+        .await;
+
+    let res = client.get_blog("jove").await;
+    assert!(matches!(res, Err(ApiError::JsonParseDetailed { .. })));
+}
+
+fn blog_cards() -> String {
+    fs::read_to_string("tests/fixtures/api_response_blog_cards.json").unwrap()
+}
+
+#[tokio::test]
+async fn test_search_blogs_encodes_query() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("search/blog/?query=night+radio%26more&limit=20");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(blog_cards())
+        .create_async()
+        .await;
+
+    let res = client
+        .search_blogs(" night radio&more ", Some(20), None)
+        .await
+        .unwrap();
+
+    assert_eq!(res.total, 2);
+    let jove = &res.data[0];
+    assert_eq!(jove.blog_url, "jove");
+    assert_eq!(jove.owner.name, "Jove");
+    assert_eq!(jove.count.posts, 1520);
+    assert_eq!(jove.count.likes, 0);
+
+    let night = &res.data[1];
+    assert!(night.has_adult_content);
+    assert!(night.description.is_none());
+}
+
+#[tokio::test]
+async fn test_search_blogs_empty_query_rejected() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let err = client.search_blogs("  ", None, None).await.unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn test_get_blog_categories() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("blog/categories/").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({ "data": [
+                { "id": 1, "title": "Music", "blogsCount": 1200 },
+                { "id": 2, "title": "Games" }
+            ] })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let res = client.get_blog_categories().await.unwrap();
+
+    assert_eq!(res.data.len(), 2);
+    assert_eq!(res.data[0].title, "Music");
+    assert_eq!(res.data[0].blogs_count, 1200);
+    assert_eq!(res.data[1].blogs_count, 0);
+}
+
+#[tokio::test]
+async fn test_discover_all_blogs_pages_by_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock(
+            "GET",
+            api_path("blog/discover/?category_id=1&limit=1").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(fs::read_to_string("tests/fixtures/api_response_blog_cards_page1.json").unwrap())
+        .expect(1)
+        .create_async()
+        .await;
+
+    server
+        .mock(
+            "GET",
+            api_path("blog/discover/?category_id=1&limit=1&offset=1").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(fs::read_to_string("tests/fixtures/api_response_blog_cards_page2.json").unwrap())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let blogs = client.discover_all_blogs(Some(1), Some(1)).await.unwrap();
+
+    let urls: Vec<&str> = blogs.iter().map(|b| b.blog_url.as_str()).collect();
+    assert_eq!(urls, vec!["jove", "night_radio"]);
+}
@@ -2,7 +2,11 @@ mod helpers;
 
 use std::fs;
 
-use boosty_api::{api_client::ApiClient, error::ApiError, model::BundleQuery};
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{BundleQuery, NewBundle},
+};
 use mockito::Matcher;
 use reqwest::{Client, header::CONTENT_TYPE};
 use serde_json::json;
@@ -366,3 +370,211 @@ async fn test_get_bundle_with_refresh_token_flow() {
     let result = client.get_bundle(blog, bundle_id, &query).await.unwrap();
     assert_eq!(result.data.bundle_items.len(), 2);
 }
+
+fn new_bundle() -> NewBundle {
+    NewBundle {
+        title: "Test Bundle One".into(),
+        description: "This is a test bundle description".into(),
+        price: 500.0,
+        hidden: false,
+        sorting: "date_desc".into(),
+    }
+}
+
+fn bundle_body() -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_bundles.json").unwrap();
+    let value: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    value["data"]["bundles"][0].to_string()
+}
+
+#[tokio::test]
+async fn test_get_all_bundle_items_follows_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "testblog";
+    let bundle_id = "bundle-001";
+
+    let first = server
+        .mock(
+            "GET",
+            Matcher::Regex(format!(
+                "^/v1/blog/{blog}/bundle/{bundle_id}/post/\\?[^?]*reply_limit=1$"
+            )),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_bundle_items_page1.json").unwrap(),
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock(
+            "GET",
+            Matcher::Regex(format!(
+                "^/v1/blog/{blog}/bundle/{bundle_id}/post/\\?.*&offset=1$"
+            )),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_bundle_items_page2.json").unwrap(),
+        )
+        .create_async()
+        .await;
+
+    let items = client
+        .get_all_bundle_items(blog, bundle_id, &BundleQuery::default())
+        .await
+        .unwrap();
+
+    let positions: Vec<u32> = items.iter().map(|item| item.position).collect();
+    assert_eq!(positions, vec![1, 2]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_bundle_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "testblog";
+    let path = api_path(&format!("blog/{blog}/bundle/"));
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("title".into(), "Test Bundle One".into()),
+            Matcher::UrlEncoded("price".into(), "500.0".into()),
+            Matcher::UrlEncoded("hidden".into(), "false".into()),
+            Matcher::UrlEncoded("sorting".into(), "date_desc".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(bundle_body())
+        .create_async()
+        .await;
+
+    let bundle = client.create_bundle(blog, &new_bundle()).await.unwrap();
+    assert_eq!(bundle.id, "bundle-001");
+    assert_eq!(bundle.title, "Test Bundle One");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_bundle_invalid_input() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blank_title = NewBundle {
+        title: " ".into(),
+        ..new_bundle()
+    };
+    let negative_price = NewBundle {
+        price: -1.0,
+        ..new_bundle()
+    };
+
+    for bundle in [blank_title, negative_price] {
+        let res = client
+            .update_bundle("testblog", "bundle-001", &bundle)
+            .await;
+        assert!(matches!(res, Err(ApiError::InvalidInput(_))));
+    }
+}
+
+#[tokio::test]
+async fn test_update_bundle_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "testblog";
+    let bundle_id = "bundle-001";
+    let path = api_path(&format!("blog/{blog}/bundle/{bundle_id}"));
+
+    let mock = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::UrlEncoded("hidden".into(), "true".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(bundle_body())
+        .create_async()
+        .await;
+
+    let bundle = NewBundle {
+        hidden: true,
+        ..new_bundle()
+    };
+    client
+        .update_bundle(blog, bundle_id, &bundle)
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_delete_bundle_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/testblog/bundle/bundle-001");
+
+    server
+        .mock("DELETE", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client.delete_bundle("testblog", "bundle-001").await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_bundle_post_management() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "testblog";
+    let bundle_id = "bundle-001";
+    let post_id = "post-bundle-002";
+    let list_path = api_path(&format!("blog/{blog}/bundle/{bundle_id}/post/"));
+    let item_path = api_path(&format!("blog/{blog}/bundle/{bundle_id}/post/{post_id}"));
+
+    let add = server
+        .mock("POST", list_path.as_str())
+        .match_body(Matcher::UrlEncoded("post_id".into(), post_id.into()))
+        .with_status(200)
+        .create_async()
+        .await;
+    let reorder = server
+        .mock("PUT", item_path.as_str())
+        .match_body(Matcher::UrlEncoded("position".into(), "1".into()))
+        .with_status(200)
+        .create_async()
+        .await;
+    let remove = server
+        .mock("DELETE", item_path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client
+        .add_bundle_post(blog, bundle_id, post_id)
+        .await
+        .unwrap();
+    client
+        .set_bundle_item_position(blog, bundle_id, post_id, 1)
+        .await
+        .unwrap();
+    client
+        .remove_bundle_post(blog, bundle_id, post_id)
+        .await
+        .unwrap();
+
+    add.assert_async().await;
+    reorder.assert_async().await;
+    remove.assert_async().await;
+}
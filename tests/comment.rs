@@ -1,7 +1,9 @@
 use std::fs;
 
 use boosty_api::{api_client::ApiClient, error::ApiError, model::CommentBlock};
+use mockito::Matcher;
 use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::Value;
 
 use crate::helpers::{api_path, setup};
 
@@ -184,3 +186,226 @@ async fn test_get_comments_response_success() {
     assert!(comments_response.extra.is_first, "Expected is_first = true");
     assert!(!comments_response.extra.is_last, "Expected is_last = false");
 }
+
+fn comment_fixture_with(field: &str, value: bool) -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_comments.json").unwrap();
+    let mut value_json: Value = serde_json::from_str(&raw).unwrap();
+    value_json[field] = Value::Bool(value);
+    value_json.to_string()
+}
+
+#[tokio::test]
+async fn test_update_comment_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog_test";
+    let post_id = "post_id_1";
+    let comment_id = 10091879;
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/comment/{comment_id}"));
+
+    let mock = server
+        .mock("PUT", path.as_str())
+        .match_header(
+            "content-type",
+            Matcher::Regex("^multipart/form-data".into()),
+        )
+        .match_body(Matcher::Regex("edited text".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(comment_fixture_with("isUpdated", true))
+        .create_async()
+        .await;
+
+    let blocks = [CommentBlock::text("edited text"), CommentBlock::text_end()];
+
+    let comment = client
+        .update_comment(blog, post_id, comment_id, &blocks)
+        .await
+        .unwrap();
+
+    assert_eq!(comment.int_id, comment_id);
+    assert!(comment.is_updated);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_comment_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog";
+    let post_id = "p";
+    let comment_id = 1;
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/comment/{comment_id}"));
+
+    server
+        .mock("PUT", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client
+        .update_comment(blog, post_id, comment_id, &[CommentBlock::text("x")])
+        .await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_delete_comment_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog_test";
+    let post_id = "post_id_1";
+    let comment_id = 10091879;
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/comment/{comment_id}"));
+
+    server
+        .mock("DELETE", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(comment_fixture_with("isDeleted", true))
+        .create_async()
+        .await;
+
+    let comment = client
+        .delete_comment(blog, post_id, comment_id)
+        .await
+        .unwrap();
+    assert!(comment.is_deleted);
+}
+
+#[tokio::test]
+async fn test_delete_comment_http_error() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog";
+    let post_id = "p";
+    let comment_id = 2;
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/comment/{comment_id}"));
+
+    server
+        .mock("DELETE", path.as_str())
+        .with_status(403)
+        .create_async()
+        .await;
+
+    let res = client.delete_comment(blog, post_id, comment_id).await;
+    assert!(matches!(res, Err(ApiError::HttpStatus { .. })));
+}
+
+#[tokio::test]
+async fn test_restore_comment_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog_test";
+    let post_id = "post_id_1";
+    let comment_id = 10091879;
+    let path = api_path(&format!(
+        "blog/{blog}/post/{post_id}/comment/{comment_id}/restore/"
+    ));
+
+    server
+        .mock("POST", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(comment_fixture_with("isDeleted", false))
+        .create_async()
+        .await;
+
+    let comment = client
+        .restore_comment(blog, post_id, comment_id)
+        .await
+        .unwrap();
+    assert!(!comment.is_deleted);
+}
+
+#[tokio::test]
+async fn test_get_comment_tree_fetches_missing_replies() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "b_tree";
+    let post_id = "p_tree";
+
+    let raw = fs::read_to_string("tests/fixtures/api_response_comments_list_page1.json").unwrap();
+    let mut roots: Value = serde_json::from_str(&raw).unwrap();
+    roots["extra"]["isLast"] = Value::Bool(true);
+
+    let reply_raw = fs::read_to_string("tests/fixtures/api_response_comments.json").unwrap();
+    let mut reply: Value = serde_json::from_str(&reply_raw).unwrap();
+    reply["intId"] = 2000.into();
+    reply["parentId"] = 1000.into();
+    reply["replyId"] = 1000.into();
+    let replies = serde_json::json!({
+        "data": [reply],
+        "extra": {"isFirst": true, "isLast": true}
+    });
+
+    server
+        .mock(
+            "GET",
+            api_path(&format!("blog/{blog}/post/{post_id}/comment/")).as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(roots.to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let replies_mock = server
+        .mock(
+            "GET",
+            api_path(&format!(
+                "blog/{blog}/post/{post_id}/comment/?parent_id=1000"
+            ))
+            .as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(replies.to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tree = client.get_comment_tree(blog, post_id, None).await.unwrap();
+
+    assert_eq!(tree.len(), 3);
+    assert_eq!(tree.thread_sizes(), vec![(1000, 2), (1001, 1)]);
+    assert_eq!(tree.parent(2000).unwrap().int_id, 1000);
+
+    let depths: Vec<(usize, u64)> = tree
+        .iter_depth_first()
+        .map(|(depth, c)| (depth, c.int_id))
+        .collect();
+    assert_eq!(depths, vec![(0, 1000), (1, 2000), (0, 1001)]);
+
+    replies_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_comment_replies_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "b";
+    let post_id = "p";
+    let path = api_path(&format!(
+        "blog/{blog}/post/{post_id}/comment/?parent_id=5&limit=10"
+    ));
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client
+        .get_comment_replies(blog, post_id, 5, Some(10), None)
+        .await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
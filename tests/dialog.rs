@@ -0,0 +1,204 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    media_content::ContentItem,
+    model::{CommentBlock, MediaBlock, MessagesResponse},
+    traits::HasContent,
+};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+
+use crate::helpers::{api_path, setup};
+
+fn messages_fixture() -> String {
+    fs::read_to_string("tests/fixtures/api_response_dialog_messages.json").unwrap()
+}
+
+fn single_message() -> String {
+    let value: serde_json::Value = serde_json::from_str(&messages_fixture()).unwrap();
+    value["data"][0].to_string()
+}
+
+#[tokio::test]
+async fn test_get_dialogs_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("dialog/?limit=20&offset=0");
+    let body = fs::read_to_string("tests/fixtures/api_response_dialogs.json").unwrap();
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let res = client.get_dialogs(Some(20), Some(0)).await.unwrap();
+
+    assert!(!res.extra.is_last);
+    assert_eq!(res.extra.offset, Some(2));
+    assert_eq!(res.data.len(), 2);
+
+    let alice = &res.data[0];
+    assert_eq!(alice.chatmate.name, "Alice");
+    assert_eq!(alice.unread_count, 2);
+    assert!(!alice.last_message.as_ref().unwrap().is_read);
+
+    let bob = &res.data[1];
+    assert_eq!(bob.unread_count, 0);
+    assert!(bob.last_message.is_none());
+}
+
+#[tokio::test]
+async fn test_get_dialog_messages_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("dialog/501/message/?limit=10&before_id=7004");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(messages_fixture())
+        .create_async()
+        .await;
+
+    let res: MessagesResponse = client
+        .get_dialog_messages(501, Some(10), Some(7004))
+        .await
+        .unwrap();
+
+    assert!(res.extra.is_last);
+    assert_eq!(res.data.len(), 2);
+    assert_eq!(res.data[0].author_id, 9001);
+
+    match &res.data[1].extract_content()[0] {
+        ContentItem::Image { url, id } => {
+            assert_eq!(url, "https://images.boosty.to/message/7002/image");
+            assert_eq!(id, "img-7002");
+        }
+        other => panic!("unexpected content: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_all_dialog_messages_pages_back() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("dialog/501/message/?limit=1").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_dialog_messages_page1.json").unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    server
+        .mock(
+            "GET",
+            api_path("dialog/501/message/?limit=1&before_id=7003").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_dialog_messages_page2.json").unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let messages = client.get_all_dialog_messages(501, Some(1)).await.unwrap();
+
+    let ids: Vec<u64> = messages.iter().map(|m| m.id).collect();
+    assert_eq!(ids, vec![7003, 7002]);
+}
+
+#[tokio::test]
+async fn test_send_message_with_image() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("dialog/501/message/");
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .match_header(
+            "content-type",
+            Matcher::Regex("^multipart/form-data".into()),
+        )
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("See you tomorrow".into()),
+            Matcher::Regex(r#"\{"type":"image","id":"img-1"\}"#.into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_message())
+        .create_async()
+        .await;
+
+    let blocks = vec![
+        CommentBlock::text("See you tomorrow"),
+        CommentBlock::text_end(),
+        CommentBlock::Image(MediaBlock { id: "img-1".into() }),
+    ];
+
+    let message = client.send_message(501, &blocks).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(message.id, 7003);
+}
+
+#[tokio::test]
+async fn test_send_message_empty_rejected() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let err = client.send_message(501, &[]).await.unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn test_mark_dialog_read() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("dialog/501/read/");
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client.mark_dialog_read(501).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_mark_dialog_read_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("dialog/501/read/");
+
+    server
+        .mock("POST", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let err = client.mark_dialog_read(501).await.unwrap_err();
+    assert!(matches!(err, ApiError::Unauthorized));
+}
@@ -0,0 +1,173 @@
+mod helpers;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{BlogFlags, Currency, DonationOutcome, DonationRequest},
+};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+use rust_decimal::dec;
+use serde_json::json;
+
+use crate::helpers::{api_path, setup};
+
+fn flags(accept_messages: bool, has_targets: bool) -> BlogFlags {
+    BlogFlags {
+        show_post_donations: false,
+        has_adult_content: false,
+        has_subscription_levels: true,
+        forbidden_change_has_adult_content: false,
+        has_targets,
+        is_alien: true,
+        allow_index: true,
+        allow_google_index: true,
+        accept_donation_messages: accept_messages,
+        is_rss_feed_enabled: false,
+    }
+}
+
+#[tokio::test]
+async fn test_donate_to_blog_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/jove/donation/");
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("amount".into(), "150.50".into()),
+            Matcher::UrlEncoded("currency".into(), "RUB".into()),
+            Matcher::UrlEncoded("message".into(), "Thanks!".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({"id": 9001, "amount": "150.50", "paymentUrl": "https://pay.example.com/9001"})
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let request = DonationRequest::new("jove", dec!(150.50), Currency::Rub).with_message("Thanks!");
+    let outcome = client
+        .donate(&request, &flags(true, true), false)
+        .await
+        .unwrap();
+
+    match outcome {
+        DonationOutcome::Sent(donation) => {
+            assert_eq!(donation.id, 9001);
+            assert_eq!(donation.amount, dec!(150.50));
+            assert!(donation.payment_url.is_some());
+        }
+        other => panic!("Expected Sent, got {other:?}"),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_donate_dry_run_does_not_send() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let request = DonationRequest::new("jove", dec!(5), Currency::Usd).for_target(600101);
+    let outcome = client
+        .donate(&request, &flags(false, true), true)
+        .await
+        .unwrap();
+
+    match outcome {
+        DonationOutcome::DryRun(prepared) => {
+            assert_eq!(prepared.path, "blog/jove/donation/");
+            assert_eq!(prepared.form.target_id, Some(600101));
+            assert_eq!(prepared.form.currency, Currency::Usd);
+            assert_eq!(prepared.form.message, None);
+        }
+        other => panic!("Expected DryRun, got {other:?}"),
+    }
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_prepare_post_donation_path() {
+    let prepared = DonationRequest::new("jove", dec!(10), Currency::Eur)
+        .for_post("post002")
+        .prepare();
+
+    assert_eq!(prepared.path, "blog/jove/post/post002/donation/");
+    assert_eq!(prepared.form.target_id, None);
+}
+
+#[tokio::test]
+async fn test_donate_validation_against_flags() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let cases = [
+        (
+            DonationRequest::new("jove", dec!(0), Currency::Rub),
+            flags(true, true),
+        ),
+        (
+            DonationRequest::new("jove", dec!(1.005), Currency::Rub),
+            flags(true, true),
+        ),
+        (
+            DonationRequest::new("jove", dec!(10), Currency::Rub).with_message("hi"),
+            flags(false, true),
+        ),
+        (
+            DonationRequest::new("jove", dec!(10), Currency::Rub).with_message("  "),
+            flags(true, true),
+        ),
+        (
+            DonationRequest::new("jove", dec!(10), Currency::Rub).for_target(1),
+            flags(true, false),
+        ),
+    ];
+
+    for (request, flags) in cases {
+        let res = client.donate(&request, &flags, true).await;
+        assert!(
+            matches!(res, Err(ApiError::InvalidInput(_))),
+            "Expected InvalidInput for {request:?}"
+        );
+    }
+}
+
+#[test]
+fn test_post_donation_ignores_show_post_donations_flag() {
+    let request = DonationRequest::new("jove", dec!(10), Currency::Rub).for_post("post002");
+    let flags = flags(true, true);
+
+    assert!(!flags.show_post_donations);
+    assert!(request.validate(&flags).is_ok());
+}
+
+#[tokio::test]
+async fn test_donate_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock(
+            "POST",
+            api_path("blog/jove/post/post002/donation/").as_str(),
+        )
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let request = DonationRequest::new("jove", dec!(10), Currency::Rub).for_post("post002");
+    let res = client.donate(&request, &flags(true, true), false).await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
@@ -0,0 +1,204 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::PostCursor,
+    scheduler::{PostScheduler, ScheduledDraft},
+};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::Value;
+
+use crate::helpers::{api_path, setup};
+
+fn posts_fixture() -> String {
+    fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap()
+}
+
+fn single_post(is_published: bool, publish_time: i64) -> String {
+    let value: Value = serde_json::from_str(&posts_fixture()).unwrap();
+    let mut post = value["data"][0].clone();
+    post["isPublished"] = is_published.into();
+    post["publishTime"] = publish_time.into();
+    post.to_string()
+}
+
+#[tokio::test]
+async fn test_get_drafts_page() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/post/draft/?limit=10&offset=1710001000:101");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(posts_fixture())
+        .create_async()
+        .await;
+
+    let res = client
+        .get_drafts("blogx", 10, Some(&PostCursor::new(1710001000, 101)))
+        .await
+        .unwrap();
+
+    assert_eq!(res.data.len(), 2);
+    assert_eq!(res.next_cursor().unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_publish_post() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("blog/blogx/post/p1/publish/").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post(true, 1720000000))
+        .create_async()
+        .await;
+
+    let post = client.publish_post("blogx", "p1").await.unwrap();
+
+    mock.assert_async().await;
+    assert!(post.is_published);
+}
+
+#[tokio::test]
+async fn test_schedule_and_unschedule_post() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/post/p1/schedule/");
+
+    let schedule = server
+        .mock("POST", path.as_str())
+        .match_body(Matcher::UrlEncoded(
+            "publish_time".into(),
+            "1999999999".into(),
+        ))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post(false, 1999999999))
+        .create_async()
+        .await;
+
+    let unschedule = server
+        .mock("DELETE", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let post = client
+        .schedule_post("blogx", "p1", 1999999999)
+        .await
+        .unwrap();
+    assert!(!post.is_published);
+    assert_eq!(post.publish_time, 1999999999);
+
+    client.unschedule_post("blogx", "p1").await.unwrap();
+
+    schedule.assert_async().await;
+    unschedule.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_publish_post_http_error() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("POST", api_path("blog/blogx/post/p1/publish/").as_str())
+        .with_status(409)
+        .create_async()
+        .await;
+
+    let err = client.publish_post("blogx", "p1").await.unwrap_err();
+    assert!(matches!(err, ApiError::HttpStatus { .. }));
+}
+
+#[tokio::test]
+async fn test_scheduler_publishes_due_drafts() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let first = server
+        .mock("POST", api_path("blog/blogx/post/p1/publish/").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post(true, 100))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let failing = server
+        .mock("POST", api_path("blog/blogx/post/p2/publish/").as_str())
+        .with_status(500)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let later = server
+        .mock("POST", api_path("blog/blogx/post/p3/publish/").as_str())
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mut scheduler = PostScheduler::new();
+    scheduler.push(ScheduledDraft::new("blogx", "p3", 300));
+    scheduler.push(ScheduledDraft::new("blogx", "p2", 200));
+    scheduler.push(ScheduledDraft::new("blogx", "p1", 100));
+
+    let results = scheduler.publish_due(&client, 250).await;
+
+    first.assert_async().await;
+    failing.assert_async().await;
+    later.assert_async().await;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].draft.post_id, "p1");
+    assert!(results[0].result.is_ok());
+    assert!(matches!(
+        results[1].result,
+        Err(ApiError::HttpStatus { .. })
+    ));
+    assert_eq!(scheduler.next_publish_at(), Some(300));
+}
+
+#[tokio::test]
+async fn test_scheduler_run_publishes_overdue_drafts() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock(
+            "POST",
+            Matcher::Regex(r"^/v1/blog/blogx/post/p[12]/publish/$".into()),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post(true, 100))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut scheduler = PostScheduler::new();
+    scheduler.push(ScheduledDraft::new("blogx", "p1", 1));
+    scheduler.push(ScheduledDraft::new("blogx", "p2", 2));
+
+    let mut published = Vec::new();
+    scheduler
+        .run(&client, |result| {
+            published.push(result.draft.post_id.clone())
+        })
+        .await;
+
+    mock.assert_async().await;
+    assert_eq!(published, vec!["p1", "p2"]);
+    assert!(scheduler.is_empty());
+}
@@ -0,0 +1,258 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{ContentKind, FeedFilter, Post, PostCursor},
+};
+use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::{Value, json};
+
+use crate::helpers::{api_path, setup};
+
+fn post_value(int_id: i64, publish_time: i64, has_access: bool, counters: Value) -> Value {
+    let fixture: Value = serde_json::from_str(
+        &fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap(),
+    )
+    .unwrap();
+    let mut post = fixture["data"][0].clone();
+    post["int_id"] = json!(int_id);
+    post["publishTime"] = json!(publish_time);
+    post["sortOrder"] = json!(publish_time);
+    post["hasAccess"] = json!(has_access);
+    post["contentCounters"] = counters;
+    post
+}
+
+fn page(posts: Vec<Value>, is_last: bool, offset: &str) -> String {
+    json!({ "data": posts, "extra": { "isLast": is_last, "offset": offset } }).to_string()
+}
+
+fn post(has_access: bool, publish_time: i64, counters: Value) -> Post {
+    serde_json::from_value(post_value(1, publish_time, has_access, counters)).unwrap()
+}
+
+#[tokio::test]
+async fn test_get_feed_page_with_cursor() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("feed/post/?limit=2&offset=300:3");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(page(
+            vec![
+                post_value(2, 200, true, json!([])),
+                post_value(1, 100, true, json!([])),
+            ],
+            false,
+            "100:1",
+        ))
+        .create_async()
+        .await;
+
+    let res = client
+        .get_feed_page(2, Some(&PostCursor::new(300, 3)))
+        .await
+        .unwrap();
+
+    assert_eq!(res.data.len(), 2);
+    assert_eq!(res.next_cursor().unwrap(), Some(PostCursor::new(100, 1)));
+}
+
+#[tokio::test]
+async fn test_get_feed_filters_and_stops_before_window() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let video = json!([{ "type": "video", "count": 1, "size": 100 }]);
+
+    server
+        .mock("GET", api_path("feed/post/?limit=2").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(page(
+            vec![
+                post_value(5, 500, true, video.clone()),
+                post_value(4, 400, false, video.clone()),
+            ],
+            false,
+            "400:4",
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    server
+        .mock("GET", api_path("feed/post/?limit=2&offset=400:4").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(page(
+            vec![
+                post_value(3, 300, true, json!([])),
+                post_value(2, 200, true, video.clone()),
+            ],
+            false,
+            "200:2",
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    server
+        .mock("GET", api_path("feed/post/?limit=2&offset=200:2").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(page(
+            vec![
+                post_value(1, 100, true, video.clone()),
+                post_value(0, 50, true, video),
+            ],
+            true,
+            "",
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let filter = FeedFilter::new()
+        .only_accessible()
+        .with_content(ContentKind::Video)
+        .published_from(150)
+        .published_to(450);
+
+    let posts = client.get_feed(10, Some(2), &filter, None).await.unwrap();
+
+    let ids: Vec<i64> = posts.iter().map(|p| p.int_id).collect();
+    assert_eq!(ids, vec![2]);
+}
+
+#[tokio::test]
+async fn test_get_feed_stops_at_limit() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("GET", api_path("feed/post/?limit=3").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(page(
+            vec![
+                post_value(3, 300, true, json!([])),
+                post_value(2, 200, true, json!([])),
+                post_value(1, 100, true, json!([])),
+            ],
+            false,
+            "100:1",
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let posts = client
+        .get_feed(2, Some(3), &FeedFilter::new(), None)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(posts.len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_feed_ignores_invalid_offset_when_done() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("feed/post/?limit=2").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(page(
+            vec![
+                post_value(2, 200, true, json!([])),
+                post_value(1, 100, true, json!([])),
+            ],
+            false,
+            "garbage",
+        ))
+        .create_async()
+        .await;
+
+    let posts = client
+        .get_feed(2, Some(2), &FeedFilter::new(), None)
+        .await
+        .unwrap();
+    assert_eq!(posts.len(), 2);
+
+    let err = client
+        .get_feed(3, Some(2), &FeedFilter::new(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidCursor(_)));
+}
+
+#[tokio::test]
+async fn test_get_feed_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("feed/post/?limit=20").as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let err = client
+        .get_feed(5, None, &FeedFilter::new(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::Unauthorized));
+}
+
+#[test]
+fn test_feed_filter_content_kinds_are_alternatives() {
+    let filter = FeedFilter::new()
+        .with_content(ContentKind::Video)
+        .with_content(ContentKind::Audio);
+
+    assert!(filter.matches(&post(
+        true,
+        0,
+        json!([{ "type": "ok_video", "count": 1, "size": 0 }])
+    )));
+    assert!(filter.matches(&post(
+        true,
+        0,
+        json!([{ "type": "audio_file", "count": 2, "size": 0 }])
+    )));
+    assert!(!filter.matches(&post(
+        true,
+        0,
+        json!([{ "type": "image", "count": 3, "size": 0 }])
+    )));
+    assert!(!filter.matches(&post(
+        true,
+        0,
+        json!([{ "type": "video", "count": 0, "size": 0 }])
+    )));
+}
+
+#[test]
+fn test_feed_filter_publish_range_inclusive() {
+    let filter = FeedFilter::new().published_from(100).published_to(200);
+
+    assert!(filter.matches(&post(false, 100, json!([]))));
+    assert!(filter.matches(&post(false, 200, json!([]))));
+    assert!(!filter.matches(&post(false, 99, json!([]))));
+    assert!(!filter.matches(&post(false, 201, json!([]))));
+    assert!(
+        !filter
+            .only_accessible()
+            .matches(&post(false, 150, json!([])))
+    );
+}
@@ -0,0 +1,167 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{api_client::ApiClient, error::ApiError};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::{Value, json};
+
+use crate::helpers::{api_path, setup};
+
+fn blacklist() -> String {
+    fs::read_to_string("tests/fixtures/api_response_blacklist.json").unwrap()
+}
+
+fn comment(is_blocked: bool) -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_comments.json").unwrap();
+    let mut value: Value = serde_json::from_str(&raw).unwrap();
+    value["isBlocked"] = json!(is_blocked);
+    value.to_string()
+}
+
+#[tokio::test]
+async fn test_block_and_unblock_user() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blocked: Value = serde_json::from_str(&blacklist()).unwrap();
+
+    let block = server
+        .mock("POST", api_path("blog/blogx/blacklist/").as_str())
+        .match_body(Matcher::UrlEncoded("user_id".into(), "40118268".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(blocked["data"][0].to_string())
+        .create_async()
+        .await;
+
+    let unblock = server
+        .mock("DELETE", api_path("blog/blogx/blacklist/40118268").as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let entry = client.block_user("blogx", 40118268).await.unwrap();
+    assert_eq!(entry.user.name, "user1");
+    assert_eq!(entry.blocked_at, 1764832800);
+
+    client.unblock_user("blogx", 40118268).await.unwrap();
+
+    block.assert_async().await;
+    unblock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_blacklist() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock(
+            "GET",
+            api_path("blog/blogx/blacklist/?limit=20&offset=0").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(blacklist())
+        .create_async()
+        .await;
+
+    let res = client
+        .get_blacklist("blogx", Some(20), Some(0))
+        .await
+        .unwrap();
+
+    assert_eq!(res.total, 2);
+    assert_eq!(res.data[1].user.id, 40118270);
+}
+
+#[tokio::test]
+async fn test_get_all_blacklist_pages_by_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("blog/blogx/blacklist/?limit=1").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(fs::read_to_string("tests/fixtures/api_response_blacklist_page1.json").unwrap())
+        .expect(1)
+        .create_async()
+        .await;
+
+    server
+        .mock(
+            "GET",
+            api_path("blog/blogx/blacklist/?limit=1&offset=1").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(fs::read_to_string("tests/fixtures/api_response_blacklist_page2.json").unwrap())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let users = client.get_all_blacklist("blogx", Some(1)).await.unwrap();
+
+    let ids: Vec<u64> = users.iter().map(|u| u.user.id).collect();
+    assert_eq!(ids, vec![40118268, 40118270]);
+}
+
+#[tokio::test]
+async fn test_hide_and_unhide_comment() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/post/pid/comment/10091879/block/");
+
+    let hide = server
+        .mock("POST", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(comment(true))
+        .create_async()
+        .await;
+
+    let unhide = server
+        .mock("DELETE", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(comment(false))
+        .create_async()
+        .await;
+
+    assert!(
+        client
+            .hide_comment("blogx", "pid", 10091879)
+            .await
+            .unwrap()
+            .is_blocked
+    );
+    assert!(
+        !client
+            .unhide_comment("blogx", "pid", 10091879)
+            .await
+            .unwrap()
+            .is_blocked
+    );
+
+    hide.assert_async().await;
+    unhide.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_block_user_forbidden() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("POST", api_path("blog/blogx/blacklist/").as_str())
+        .with_status(403)
+        .create_async()
+        .await;
+
+    let err = client.block_user("blogx", 1).await.unwrap_err();
+    assert!(matches!(err, ApiError::HttpStatus { .. }));
+}
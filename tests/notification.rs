@@ -0,0 +1,143 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{NotificationKind, ReactionKind},
+    traits::HasContent,
+};
+use reqwest::{Client, header::CONTENT_TYPE};
+use rust_decimal::dec;
+
+use crate::helpers::{api_path, setup};
+
+fn fixture() -> String {
+    fs::read_to_string("tests/fixtures/api_response_notifications.json").unwrap()
+}
+
+#[tokio::test]
+async fn test_get_notifications_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("notification/?limit=20");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(fixture())
+        .create_async()
+        .await;
+
+    let res = client.get_notifications(Some(20), None).await.unwrap();
+
+    assert!(res.extra.is_last);
+    assert_eq!(res.data.len(), 4);
+
+    let reply = &res.data[0];
+    assert_eq!(reply.kind, NotificationKind::Reply);
+    assert!(!reply.is_read);
+    let comment = reply.comment.as_ref().unwrap();
+    assert_eq!(comment.int_id, 10091879);
+    assert!(!comment.extract_content().is_empty());
+    assert_eq!(
+        reply.post.as_ref().unwrap().id,
+        "94f64d66-d219-4838-88aa-47b602a2ca3d"
+    );
+
+    let donation = &res.data[1];
+    assert_eq!(donation.kind, NotificationKind::Donation);
+    assert_eq!(donation.amount, Some(dec!(150.50)));
+    assert!(donation.comment.is_none());
+
+    let reaction = &res.data[2];
+    assert_eq!(reaction.kind, NotificationKind::Reaction);
+    assert_eq!(reaction.reaction, Some(ReactionKind::Heart));
+
+    let unknown = &res.data[3];
+    assert_eq!(unknown.kind, NotificationKind::Unknown);
+    assert!(unknown.author.is_none());
+}
+
+#[tokio::test]
+async fn test_get_all_notifications_follows_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("notification/?limit=1").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_notifications_page1.json").unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    server
+        .mock("GET", api_path("notification/?limit=1&offset=1").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_notifications_page2.json").unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let all = client.get_all_notifications(Some(1)).await.unwrap();
+
+    let ids: Vec<u64> = all.iter().map(|n| n.id).collect();
+    assert_eq!(ids, vec![3002, 3001]);
+}
+
+#[tokio::test]
+async fn test_mark_notification_read() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("notification/3003/read/").as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client.mark_notification_read(3003).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_mark_all_notifications_read() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("notification/read/").as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client.mark_all_notifications_read().await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_notifications_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("notification/").as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let err = client.get_notifications(None, None).await.unwrap_err();
+    assert!(matches!(err, ApiError::Unauthorized));
+}
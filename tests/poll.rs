@@ -0,0 +1,203 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    media_content::ContentItem,
+    model::{MediaData, PollData, Post},
+    traits::HasContent,
+};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::{Value, json};
+
+use crate::helpers::{api_path, setup};
+
+fn poll_fixture() -> String {
+    fs::read_to_string("tests/fixtures/api_response_poll.json").unwrap()
+}
+
+fn poll(update: Value) -> PollData {
+    let mut value: Value = serde_json::from_str(&poll_fixture()).unwrap();
+    for (key, field) in update.as_object().unwrap() {
+        value[key] = field.clone();
+    }
+    serde_json::from_value(value).unwrap()
+}
+
+#[tokio::test]
+async fn test_post_with_poll_is_typed() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let raw = fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap();
+    let value: Value = serde_json::from_str(&raw).unwrap();
+    let mut post = value["data"][0].clone();
+    let mut poll_block: Value = serde_json::from_str(&poll_fixture()).unwrap();
+    poll_block["type"] = json!("poll");
+    post["data"] = json!([poll_block]);
+
+    server
+        .mock("GET", api_path("blog/blogx/post/pid").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(post.to_string())
+        .create_async()
+        .await;
+
+    let post = client.get_post("blogx", "pid").await.unwrap();
+
+    let MediaData::Poll(poll) = &post.data[0] else {
+        panic!("expected poll, got {:?}", post.data[0]);
+    };
+    assert!(!poll.has_voted());
+    assert_eq!(poll.percent(1), Some(75.0));
+    assert_eq!(poll.percent(9), None);
+
+    match &post.extract_content()[0] {
+        ContentItem::Poll {
+            id,
+            options,
+            total_votes,
+            ..
+        } => {
+            assert_eq!(*id, 77);
+            assert_eq!(options[1].text, "Sunday");
+            assert_eq!(*total_votes, 4);
+        }
+        other => panic!("unexpected content: {other:?}"),
+    }
+}
+
+#[test]
+fn test_post_with_partial_poll_still_parses() {
+    let raw = fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap();
+    let value: Value = serde_json::from_str(&raw).unwrap();
+    let mut post = value["data"][0].clone();
+    post["data"] = json!([{ "type": "poll", "id": 78, "title": "Draft poll" }]);
+
+    let post: Post = serde_json::from_value(post).unwrap();
+
+    let MediaData::Poll(poll) = &post.data[0] else {
+        panic!("expected poll, got {:?}", post.data[0]);
+    };
+    assert!(!poll.is_multiple);
+    assert!(!poll.is_closed);
+    assert!(poll.options.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_poll() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("blog/blogx/poll/77/").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(poll_fixture())
+        .create_async()
+        .await;
+
+    let poll = client.get_poll("blogx", 77).await.unwrap();
+
+    assert_eq!(poll.title, "Next stream?");
+    assert_eq!(poll.finish_time, Some(1765000000));
+}
+
+#[tokio::test]
+async fn test_vote_poll() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("blog/blogx/poll/77/vote/").as_str())
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(Matcher::UrlEncoded("answer[]".into(), "2".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({
+                "id": 77,
+                "title": "Next stream?",
+                "isMultiple": false,
+                "isClosed": false,
+                "options": [
+                    { "id": 1, "text": "Friday", "votes": 3 },
+                    { "id": 2, "text": "Sunday", "votes": 2 }
+                ],
+                "totalVotes": 5,
+                "answer": [2]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let updated = client
+        .vote_poll("blogx", &poll(json!({})), &[2])
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(updated.has_voted());
+    assert_eq!(updated.options[1].votes, 2);
+}
+
+#[tokio::test]
+async fn test_vote_poll_multiple_answers_form() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mut response: Value = serde_json::from_str(&poll_fixture()).unwrap();
+    response["isMultiple"] = json!(true);
+    response["answer"] = json!([1, 2]);
+
+    let mock = server
+        .mock("POST", api_path("blog/blogx/poll/77/vote/").as_str())
+        .match_body(Matcher::Exact("answer%5B%5D=1&answer%5B%5D=2".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(response.to_string())
+        .create_async()
+        .await;
+
+    client
+        .vote_poll("blogx", &poll(json!({ "isMultiple": true })), &[1, 2])
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_vote_poll_invalid_votes_not_sent() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("blog/blogx/poll/77/vote/").as_str())
+        .expect(0)
+        .create_async()
+        .await;
+
+    let single = poll(json!({}));
+    let multiple = poll(json!({ "isMultiple": true }));
+    let closed = poll(json!({ "isClosed": true }));
+
+    for (poll, options) in [
+        (&single, vec![]),
+        (&single, vec![1, 2]),
+        (&single, vec![9]),
+        (&multiple, vec![1, 1]),
+        (&closed, vec![1]),
+    ] {
+        let err = client.vote_poll("blogx", poll, &options).await.unwrap_err();
+        assert!(matches!(err, ApiError::InvalidInput(_)), "{options:?}");
+    }
+
+    assert!(multiple.validate_vote(&[1, 2]).is_ok());
+    mock.assert_async().await;
+}
@@ -1,6 +1,11 @@
 use std::fs;
 
-use boosty_api::{api_client::ApiClient, error::ApiError};
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{PostAccess, PostCursor, PostSettings},
+};
+use mockito::Matcher;
 use reqwest::{Client, header::CONTENT_TYPE};
 use serde_json::{Value, json};
 
@@ -306,3 +311,538 @@ async fn test_set_refresh_and_get_post_header_and_flow() {
     let result = client.get_post(blog, post_id).await.unwrap();
     assert_eq!(result.id, "55");
 }
+
+#[tokio::test]
+async fn test_get_posts_page_returns_next_cursor() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog";
+    let cursor = PostCursor::new(1710002000, 102);
+    let api_path = api_path(&format!("blog/{blog}/post/?limit=2&offset={cursor}"));
+
+    let raw = fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap();
+    let mut value: Value = serde_json::from_str(&raw).unwrap();
+    value["extra"] = json!({"isLast": false, "offset": "1710000001:100"});
+
+    server
+        .mock("GET", api_path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(value.to_string())
+        .create_async()
+        .await;
+
+    let page = client.get_posts_page(blog, 2, Some(&cursor)).await.unwrap();
+    let next = page.next_cursor().unwrap().unwrap();
+
+    assert_eq!(next, PostCursor::new(1710000001, 100));
+    assert_eq!(next, page.data[0].cursor());
+    assert!(next < cursor);
+}
+
+#[tokio::test]
+async fn test_get_posts_resumes_from_cursor() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog";
+    let raw = fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap();
+
+    let mut first: Value = serde_json::from_str(&raw).unwrap();
+    first["data"].as_array_mut().unwrap().truncate(1);
+    first["extra"] = json!({"isLast": false, "offset": "1710000001:100"});
+
+    let mut second: Value = serde_json::from_str(&raw).unwrap();
+    second["data"].as_array_mut().unwrap().remove(0);
+    second["extra"] = json!({"isLast": true, "offset": ""});
+
+    server
+        .mock("GET", api_path(&format!("blog/{blog}/post/?limit=1")).as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(first.to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    server
+        .mock(
+            "GET",
+            api_path(&format!("blog/{blog}/post/?limit=1&offset=1710000001:100")).as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(second.to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let posts = client.get_posts(blog, 5, Some(1), None).await.unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].id, "post001");
+    assert_eq!(posts[1].id, "post002");
+}
+
+#[tokio::test]
+async fn test_get_posts_page_invalid_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog";
+    let raw = fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap();
+    let mut value: Value = serde_json::from_str(&raw).unwrap();
+    value["extra"] = json!({"isLast": false, "offset": "garbage"});
+
+    server
+        .mock("GET", api_path(&format!("blog/{blog}/post/?limit=2")).as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(value.to_string())
+        .create_async()
+        .await;
+
+    let page = client.get_posts_page(blog, 2, None).await.unwrap();
+    assert!(matches!(page.next_cursor(), Err(ApiError::InvalidCursor(_))));
+}
+
+#[tokio::test]
+async fn test_get_posts_ignores_invalid_offset_when_limit_reached() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog";
+    let raw = fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap();
+    let mut value: Value = serde_json::from_str(&raw).unwrap();
+    value["extra"] = json!({"isLast": false, "offset": "garbage"});
+
+    server
+        .mock(
+            "GET",
+            api_path(&format!("blog/{blog}/post/?limit=2")).as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(value.to_string())
+        .create_async()
+        .await;
+
+    let posts = client.get_posts(blog, 2, None, None).await.unwrap();
+    assert_eq!(posts.len(), 2);
+
+    let err = client.get_posts(blog, 3, Some(2), None).await.unwrap_err();
+    assert!(matches!(err, ApiError::InvalidCursor(_)));
+}
+
+#[tokio::test]
+async fn test_get_post_typed_comments_donators_and_advertiser() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blog";
+    let post_id = "42";
+    let path = api_path(&format!("blog/{blog}/post/{post_id}"));
+
+    let raw = fs::read_to_string("tests/fixtures/api_response_video_image.json").unwrap();
+    let comment_raw = fs::read_to_string("tests/fixtures/api_response_comments.json").unwrap();
+    let comment: Value = serde_json::from_str(&comment_raw).unwrap();
+
+    let mut value: Value = serde_json::from_str(&raw).unwrap();
+    value["comments"] = json!({"extra": {"isLast": false}, "data": [comment]});
+    value["donators"] = json!({
+        "extra": {"isLast": true},
+        "data": [{
+            "user": {
+                "id": 7,
+                "name": "donor",
+                "hasAvatar": false,
+                "avatarUrl": ""
+            },
+            "amount": 150.5
+        }]
+    });
+    value["advertiserInfo"] = json!({"name": "ACME", "inn": "1234567890", "erid": "2Vtzq"});
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(value.to_string())
+        .create_async()
+        .await;
+
+    let post = client.get_post(blog, post_id).await.unwrap();
+
+    assert_eq!(post.comments.data.len(), 1);
+    assert_eq!(post.comments.data[0].int_id, 10091879);
+    assert_eq!(post.comments.data[0].author.name, "user1");
+
+    assert_eq!(post.donators.data.len(), 1);
+    assert_eq!(post.donators.data[0].user.name, "donor");
+    assert_eq!(post.donators.data[0].amount.to_string(), "150.5");
+
+    let advertiser = post.advertiser_info.unwrap();
+    assert_eq!(advertiser.name.as_deref(), Some("ACME"));
+    assert_eq!(advertiser.erid.as_deref(), Some("2Vtzq"));
+}
+
+fn single_post_body(update: Value) -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_posts.json").unwrap();
+    let value: Value = serde_json::from_str(&raw).unwrap();
+    let mut post = value["data"][0].clone();
+    for (key, field) in update.as_object().unwrap() {
+        post[key] = field.clone();
+    }
+    post.to_string()
+}
+
+#[tokio::test]
+async fn test_update_post_settings_sends_only_changed_flags() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/post/p1/settings/");
+
+    let mock = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::Json(
+            json!({ "is_comments_denied": true, "reactions_disabled": true }),
+        ))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post_body(
+            json!({ "isCommentsDenied": true, "reactionsDisabled": true }),
+        ))
+        .create_async()
+        .await;
+
+    let settings = PostSettings::new()
+        .comments_denied(true)
+        .reactions_disabled(true);
+    let post = client
+        .update_post_settings("blogx", "p1", &settings)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(post.is_comments_denied);
+    assert!(post.reactions_disabled);
+}
+
+#[tokio::test]
+async fn test_pin_and_unpin_post() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/post/p1/settings/");
+
+    let pin = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::Json(json!({ "is_pinned": true })))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post_body(json!({ "isPinned": true })))
+        .create_async()
+        .await;
+
+    let unpin = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::Json(json!({ "is_pinned": false })))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post_body(json!({ "isPinned": false })))
+        .create_async()
+        .await;
+
+    assert!(client.pin_post("blogx", "p1").await.unwrap().is_pinned);
+    assert!(!client.unpin_post("blogx", "p1").await.unwrap().is_pinned);
+
+    pin.assert_async().await;
+    unpin.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_post_settings_empty_rejected() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let err = client
+        .update_post_settings("blogx", "p1", &PostSettings::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn test_set_post_access() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/post/p1/access/");
+
+    let level = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::UrlEncoded(
+            "subscription_level_id".into(),
+            "42".into(),
+        ))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post_body(json!({})))
+        .create_async()
+        .await;
+
+    let price = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("subscription_level_id".into(), "0".into()),
+            Matcher::UrlEncoded("price".into(), "250.0".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_post_body(json!({ "price": 250.0 })))
+        .create_async()
+        .await;
+
+    client
+        .set_post_access("blogx", "p1", PostAccess::Level(42))
+        .await
+        .unwrap();
+    let post = client
+        .set_post_access("blogx", "p1", PostAccess::Price(250.0))
+        .await
+        .unwrap();
+
+    level.assert_async().await;
+    price.assert_async().await;
+    assert_eq!(post.price, 250.0);
+}
+
+#[tokio::test]
+async fn test_set_post_access_invalid() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    for access in [
+        PostAccess::Level(0),
+        PostAccess::Price(0.0),
+        PostAccess::Price(f64::NAN),
+    ] {
+        let err = client
+            .set_post_access("blogx", "p1", access)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::InvalidInput(_)));
+    }
+}
+
+fn posts_page_body(posts: &[(i64, i64)], is_last: bool, offset: &str) -> String {
+    let data: Vec<Value> = posts
+        .iter()
+        .map(|&(int_id, time)| {
+            serde_json::from_str(&single_post_body(
+                json!({ "int_id": int_id, "sortOrder": time, "publishTime": time }),
+            ))
+            .unwrap()
+        })
+        .collect();
+    json!({ "data": data, "extra": { "isLast": is_last, "offset": offset } }).to_string()
+}
+
+#[tokio::test]
+async fn test_search_posts_encodes_query_and_cursor() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/post/search/?query=live+stream&limit=10&offset=500%3A5");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(posts_page_body(&[(4, 400)], true, ""))
+        .create_async()
+        .await;
+
+    let res = client
+        .search_posts("blogx", "live stream", 10, Some(&PostCursor::new(500, 5)))
+        .await
+        .unwrap();
+
+    assert_eq!(res.data[0].int_id, 4);
+    assert_eq!(res.next_cursor().unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_search_posts_empty_query_rejected() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let err = client
+        .search_posts("blogx", "", 10, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn test_get_posts_between_stops_when_leaving_window() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let first = server
+        .mock(
+            "GET",
+            api_path("blog/blogx/post/?limit=2").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(posts_page_body(&[(6, 300), (5, 250)], false, "250:5"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let second = server
+        .mock(
+            "GET",
+            api_path("blog/blogx/post/?limit=2&offset=250:5").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(posts_page_body(&[(4, 200), (3, 150)], false, "150:3"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let beyond = server
+        .mock(
+            "GET",
+            api_path("blog/blogx/post/?limit=2&offset=150:3").as_str(),
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    let posts = client
+        .get_posts_between("blogx", 200, 300, Some(2))
+        .await
+        .unwrap();
+
+    first.assert_async().await;
+    second.assert_async().await;
+    beyond.assert_async().await;
+
+    let ids: Vec<i64> = posts.iter().map(|p| p.int_id).collect();
+    assert_eq!(ids, vec![6, 5, 4]);
+}
+
+#[tokio::test]
+async fn test_get_posts_between_keeps_scanning_when_sort_order_differs() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let post = |int_id: i64, sort_order: i64, publish_time: i64| -> Value {
+        serde_json::from_str(&single_post_body(json!({
+            "int_id": int_id,
+            "sortOrder": sort_order,
+            "publishTime": publish_time,
+        })))
+        .unwrap()
+    };
+
+    let first = server
+        .mock(
+            "GET",
+            api_path("blog/blogx/post/?limit=2").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({
+                "data": [post(6, 300, 300), post(5, 150, 220)],
+                "extra": { "isLast": false, "offset": "150:5" },
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let second = server
+        .mock(
+            "GET",
+            api_path("blog/blogx/post/?limit=2&offset=150:5").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({
+                "data": [post(4, 250, 100), post(3, 140, 140), post(2, 130, 210)],
+                "extra": { "isLast": false, "offset": "garbage" },
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let posts = client
+        .get_posts_between("blogx", 200, 300, Some(2))
+        .await
+        .unwrap();
+
+    first.assert_async().await;
+    second.assert_async().await;
+
+    let ids: Vec<i64> = posts.iter().map(|p| p.int_id).collect();
+    assert_eq!(ids, vec![6, 5]);
+}
+
+#[tokio::test]
+async fn test_get_posts_between_includes_post_sorted_after_window() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let post = |int_id: i64, sort_order: i64, publish_time: i64| -> Value {
+        serde_json::from_str(&single_post_body(json!({
+            "int_id": int_id,
+            "sortOrder": sort_order,
+            "publishTime": publish_time,
+        })))
+        .unwrap()
+    };
+
+    server
+        .mock("GET", api_path("blog/blogx/post/?limit=3").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({
+                "data": [post(9, 500, 500), post(8, 450, 250), post(7, 100, 100)],
+                "extra": { "isLast": false, "offset": "100:7" },
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let posts = client
+        .get_posts_between("blogx", 200, 300, Some(3))
+        .await
+        .unwrap();
+
+    let ids: Vec<i64> = posts.iter().map(|p| p.int_id).collect();
+    assert_eq!(ids, vec![8]);
+}
+
+#[tokio::test]
+async fn test_get_posts_between_invalid_range() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let err = client
+        .get_posts_between("blogx", 300, 200, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+}
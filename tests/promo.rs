@@ -0,0 +1,158 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{Access, NewPromo},
+};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+
+use crate::helpers::{api_path, setup};
+
+fn new_subscribers() -> Access {
+    Access {
+        new_subscriber: true,
+        ..Default::default()
+    }
+}
+
+fn promo_body(index: usize) -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_promos.json").unwrap();
+    let value: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    value["data"][index].to_string()
+}
+
+#[tokio::test]
+async fn test_create_promo_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let level_id = 1;
+    let path = api_path(&format!("blog/{blog}/subscription_level/{level_id}/promo/"));
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("discount_percent".into(), "20".into()),
+            Matcher::UrlEncoded("start_time".into(), "1767225600".into()),
+            Matcher::UrlEncoded("end_time".into(), "1769904000".into()),
+            Matcher::UrlEncoded("max_activation".into(), "100".into()),
+            Matcher::UrlEncoded("access_other_level_subscriber".into(), "false".into()),
+            Matcher::UrlEncoded("new_subscriber".into(), "true".into()),
+            Matcher::UrlEncoded("old_paid_subscriber".into(), "false".into()),
+            Matcher::UrlEncoded("description".into(), "Spring sale".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(promo_body(0))
+        .create_async()
+        .await;
+
+    let promo = NewPromo::new(20, 1767225600, new_subscribers())
+        .with_end_time(1769904000)
+        .with_max_activation(100)
+        .with_description("Spring sale");
+
+    let created = client.create_promo(blog, level_id, &promo).await.unwrap();
+    assert_eq!(created.id, 501);
+    assert_eq!(created.discount.percent, 20);
+    assert_eq!(created.count.max_activation, Some(100));
+    assert_eq!(created.access, new_subscribers());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_promo_invalid_input() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let cases = [
+        NewPromo::new(0, 100, new_subscribers()),
+        NewPromo::new(100, 100, new_subscribers()),
+        NewPromo::new(10, 100, new_subscribers()).with_end_time(100),
+        NewPromo::new(10, 100, new_subscribers()).with_max_activation(0),
+        NewPromo::new(10, 100, Access::default()),
+    ];
+
+    for promo in cases {
+        let res = client.create_promo("blogx", 1, &promo).await;
+        assert!(
+            matches!(res, Err(ApiError::InvalidInput(_))),
+            "Expected InvalidInput for {promo:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_get_promos_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let level_id = 1;
+    let path = api_path(&format!("blog/{blog}/subscription_level/{level_id}/promo/"));
+    let raw = fs::read_to_string("tests/fixtures/api_response_promos.json").unwrap();
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(raw)
+        .create_async()
+        .await;
+
+    let promos = client.get_promos(blog, level_id).await.unwrap();
+    assert_eq!(promos.data.len(), 2);
+    assert!(!promos.data[0].is_finished);
+    assert!(promos.data[1].is_finished);
+    assert_eq!(promos.data[1].end_time, None);
+    assert_eq!(promos.data[1].count.max_activation, None);
+}
+
+#[tokio::test]
+async fn test_get_promos_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("blog/blogx/subscription_level/1/promo/");
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client.get_promos("blogx", 1).await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_finish_promo_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let level_id = 1;
+    let promo_id = 502;
+    let path = api_path(&format!(
+        "blog/{blog}/subscription_level/{level_id}/promo/{promo_id}/finish/"
+    ));
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(promo_body(1))
+        .create_async()
+        .await;
+
+    let promo = client.finish_promo(blog, level_id, promo_id).await.unwrap();
+    assert_eq!(promo.id, promo_id);
+    assert!(promo.is_finished);
+    mock.assert_async().await;
+}
@@ -0,0 +1,170 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{Comment, ReactionKind, Reactions},
+};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+
+use crate::helpers::{api_path, setup};
+
+#[tokio::test]
+async fn test_set_post_reaction_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let post_id = "pid";
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/reaction/"));
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(Matcher::UrlEncoded("type".into(), "call-me".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let res = client
+        .set_post_reaction(blog, post_id, &ReactionKind::CallMe)
+        .await;
+    assert!(res.is_ok(), "Expected Ok: {:?}", res.err());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_set_post_reaction_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let post_id = "pid";
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/reaction/"));
+
+    server
+        .mock("POST", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client
+        .set_post_reaction(blog, post_id, &ReactionKind::Heart)
+        .await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_remove_post_reaction_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let post_id = "pid";
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/reaction/"));
+
+    let mock = server
+        .mock("DELETE", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let res = client.remove_post_reaction(blog, post_id).await;
+    assert!(res.is_ok());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_like_and_unlike_post() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let post_id = "pid";
+    let path = api_path(&format!("blog/{blog}/post/{post_id}/like/"));
+
+    let like = server
+        .mock("POST", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+    let unlike = server
+        .mock("DELETE", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client.like_post(blog, post_id).await.unwrap();
+    client.unlike_post(blog, post_id).await.unwrap();
+
+    like.assert_async().await;
+    unlike.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_like_comment_sends_like_reaction() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let comment_id = 1001;
+    let path = api_path(&format!("blog/{blog}/comment/{comment_id}/reaction/"));
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .match_body(Matcher::UrlEncoded("type".into(), "like".into()))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client.like_comment(blog, comment_id).await.unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_remove_comment_reaction_http_error() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let comment_id = 1001;
+    let path = api_path(&format!("blog/{blog}/comment/{comment_id}/reaction/"));
+
+    server
+        .mock("DELETE", path.as_str())
+        .with_status(500)
+        .create_async()
+        .await;
+
+    let res = client.remove_comment_reaction(blog, comment_id).await;
+    assert!(matches!(res, Err(ApiError::HttpStatus { .. })));
+}
+
+#[test]
+fn test_comment_reaction_counters_are_typed() {
+    let raw = fs::read_to_string("tests/fixtures/api_response_comments.json").unwrap();
+    let comment: Comment = serde_json::from_str(&raw).unwrap();
+
+    assert_eq!(comment.reaction_counters[0].type_, ReactionKind::Like);
+    assert!(
+        comment
+            .reaction_counters
+            .iter()
+            .any(|c| c.type_ == ReactionKind::FoldedHands)
+    );
+    assert!(
+        comment
+            .reaction_counters
+            .iter()
+            .all(|c| !matches!(c.type_, ReactionKind::Unknown(_)))
+    );
+    assert_eq!(
+        Reactions::from(comment.reaction_counters.as_slice()),
+        comment.reactions
+    );
+}
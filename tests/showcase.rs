@@ -1,8 +1,10 @@
 mod helpers;
 
 use crate::helpers::{api_path, setup};
-use boosty_api::{api_client::ApiClient, error::ApiError};
+use boosty_api::{api_client::ApiClient, error::ApiError, model::ShowcaseItemType};
+use mockito::Matcher;
 use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::json;
 use std::fs;
 
 #[tokio::test]
@@ -29,6 +31,7 @@ async fn test_get_showcase_success() {
         .unwrap();
 
     assert!(!resp.data.showcase_items.is_empty());
+    assert_eq!(resp.data.showcase_items[0].kind(), ShowcaseItemType::Post);
 }
 
 #[tokio::test]
@@ -130,3 +133,177 @@ async fn test_change_showcase_status_http_error() {
     let res = client.change_showcase_status(blog, false).await;
     assert!(matches!(res, Err(ApiError::HttpStatus { .. })));
 }
+
+fn showcase_page(offset: i64, is_last: bool, showcase_item_id: i64) -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_showcase.json").unwrap();
+    let mut value: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    value["data"]["showcaseItems"][0]["showcaseItemId"] = json!(showcase_item_id);
+    value["extra"]["offset"] = json!(offset);
+    value["extra"]["isLast"] = json!(is_last);
+    value.to_string()
+}
+
+#[tokio::test]
+async fn test_get_all_showcase_items_follows_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+
+    let first = server
+        .mock(
+            "GET",
+            api_path(&format!("blog/{blog}/showcase/?limit=1")).as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(showcase_page(1, false, 1))
+        .create_async()
+        .await;
+    let second = server
+        .mock(
+            "GET",
+            api_path(&format!("blog/{blog}/showcase/?offset=1&limit=1")).as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(showcase_page(2, true, 2))
+        .create_async()
+        .await;
+
+    let items = client
+        .get_all_showcase_items(blog, Some(1), None)
+        .await
+        .unwrap();
+
+    let ids: Vec<i64> = items.iter().map(|item| item.showcase_item_id).collect();
+    assert_eq!(ids, vec![1, 2]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_all_showcase_items_ignores_offset_on_last_page() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("blog/blogx/showcase/?limit=1").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(showcase_page(-1, true, 1))
+        .create_async()
+        .await;
+
+    let items = client
+        .get_all_showcase_items("blogx", Some(1), None)
+        .await
+        .unwrap();
+    assert_eq!(items.len(), 1);
+}
+
+#[tokio::test]
+async fn test_add_and_remove_showcase_item() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+
+    let add = server
+        .mock("POST", api_path(&format!("blog/{blog}/showcase/")).as_str())
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("item_type".into(), "bundle".into()),
+            Matcher::UrlEncoded("item_id".into(), "bundle-001".into()),
+        ]))
+        .with_status(200)
+        .create_async()
+        .await;
+    let remove = server
+        .mock(
+            "DELETE",
+            api_path(&format!("blog/{blog}/showcase/7")).as_str(),
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client
+        .add_showcase_item(blog, ShowcaseItemType::Bundle, "bundle-001")
+        .await
+        .unwrap();
+    client.remove_showcase_item(blog, 7).await.unwrap();
+
+    add.assert_async().await;
+    remove.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_add_showcase_item_unknown_type() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let res = client
+        .add_showcase_item("blogx", ShowcaseItemType::Unknown, "x")
+        .await;
+    assert!(matches!(res, Err(ApiError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_set_showcase_item_visibility() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+
+    let mock = server
+        .mock("PUT", api_path(&format!("blog/{blog}/showcase/7")).as_str())
+        .match_body(Matcher::UrlEncoded("is_visible".into(), "false".into()))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client
+        .set_showcase_item_visibility(blog, 7, false)
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_reorder_showcase_items_single_request() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+
+    let mock = server
+        .mock(
+            "PUT",
+            api_path(&format!("blog/{blog}/showcase/order/")).as_str(),
+        )
+        .match_header("content-type", "application/json")
+        .match_body(Matcher::Json(json!({"showcase_item_ids": [3, 1, 2]})))
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    client
+        .reorder_showcase_items(blog, &[3, 1, 2])
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_reorder_showcase_items_invalid_input() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let empty = client.reorder_showcase_items("blogx", &[]).await;
+    assert!(matches!(empty, Err(ApiError::InvalidInput(_))));
+
+    let duplicate = client.reorder_showcase_items("blogx", &[1, 2, 1]).await;
+    assert!(matches!(duplicate, Err(ApiError::InvalidInput(_))));
+}
@@ -0,0 +1,164 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{IncomeSource, TimeBucket},
+};
+use reqwest::{Client, header::CONTENT_TYPE};
+use rust_decimal::dec;
+use serde_json::json;
+
+use crate::helpers::{api_path, setup};
+
+const FROM: i64 = 1706745600;
+const TO: i64 = 1711929600;
+
+async fn mock_income(server: &mut mockito::Server, blog: &str) -> mockito::Mock {
+    let path = api_path(&format!("blog/{blog}/stats/income/?from={FROM}&to={TO}"));
+    let raw = fs::read_to_string("tests/fixtures/api_response_income.json").unwrap();
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(raw)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_get_income_stats_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    mock_income(&mut server, "blogx").await;
+
+    let income = client.get_income_stats("blogx", FROM, TO).await.unwrap();
+    assert_eq!(income.data.len(), 6);
+    assert_eq!(income.data[0].source, IncomeSource::Subscription);
+    assert_eq!(income.data[5].source, IncomeSource::Unknown);
+}
+
+#[tokio::test]
+async fn test_get_income_summary_is_exact() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    mock_income(&mut server, "blogx").await;
+
+    let summary = client.get_income_summary("blogx", FROM, TO).await.unwrap();
+    assert_eq!(summary.subscriptions, dec!(10.20));
+    assert_eq!(summary.donations, dec!(0.20));
+    assert_eq!(summary.posts, dec!(2.50));
+    assert_eq!(summary.bundles, dec!(10.00));
+    assert_eq!(summary.other, dec!(0.10));
+    assert_eq!(summary.total(), dec!(23.00));
+}
+
+#[tokio::test]
+async fn test_get_income_report_by_month_and_week() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    mock_income(&mut server, "blogx").await;
+    let monthly = client
+        .get_income_report("blogx", FROM, TO, TimeBucket::Month)
+        .await
+        .unwrap();
+
+    // February and March 2024.
+    let starts: Vec<i64> = monthly.iter().map(|(start, _)| *start).collect();
+    assert_eq!(starts, vec![1706745600, 1709251200]);
+    assert_eq!(monthly[0].1.total(), dec!(12.90));
+    assert_eq!(monthly[1].1.total(), dec!(10.10));
+
+    mock_income(&mut server, "blogx").await;
+    let weekly = client
+        .get_income_report("blogx", FROM, TO, TimeBucket::Week)
+        .await
+        .unwrap();
+    assert_eq!(weekly.len(), 2);
+    assert_eq!(weekly[1].0, 1708905600);
+    assert_eq!(weekly[1].1.subscriptions, dec!(5.10));
+}
+
+#[tokio::test]
+async fn test_get_subscriber_stats_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path(&format!(
+        "blog/blogx/stats/subscribers/?from={FROM}&to={TO}"
+    ));
+    let body = json!({
+        "data": [
+            {"time": 1706745600, "subscribers": 10, "followers": 120},
+            {"time": 1709251200, "subscribers": 12, "followers": 131}
+        ]
+    });
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(body.to_string())
+        .create_async()
+        .await;
+
+    let stats = client
+        .get_subscriber_stats("blogx", FROM, TO)
+        .await
+        .unwrap();
+    assert_eq!(stats.data.len(), 2);
+    assert_eq!(stats.data[1].subscribers, 12);
+    assert_eq!(stats.data[1].followers, 131);
+}
+
+#[tokio::test]
+async fn test_get_post_views_stats_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path(&format!("blog/blogx/stats/posts/?from={FROM}&to={TO}"));
+    let body = json!({
+        "data": [
+            {"postId": "post002", "title": "Post Two", "publishedAt": 1710001000, "views": 42},
+            {"postId": "post003", "title": null, "publishedAt": 1710002000, "views": 0}
+        ]
+    });
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(body.to_string())
+        .create_async()
+        .await;
+
+    let views = client
+        .get_post_views_stats("blogx", FROM, TO)
+        .await
+        .unwrap();
+    assert_eq!(views.data[0].views, 42);
+    assert_eq!(views.data[1].title, None);
+}
+
+#[tokio::test]
+async fn test_get_income_stats_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path(&format!("blog/blogx/stats/income/?from={FROM}&to={TO}"));
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client.get_income_summary("blogx", FROM, TO).await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
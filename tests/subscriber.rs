@@ -0,0 +1,153 @@
+mod helpers;
+
+use std::fs;
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    export::{SUBSCRIBER_CSV_HEADER, subscribers_to_csv, subscribers_to_json},
+    model::{SubscribersResponse, SubscriptionStatus},
+};
+use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::json;
+
+use crate::helpers::{api_path, setup};
+
+fn fixture() -> String {
+    fs::read_to_string("tests/fixtures/api_response_subscribers.json").unwrap()
+}
+
+#[tokio::test]
+async fn test_get_blog_subscribers_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let path = api_path(&format!(
+        "blog/{blog}/subscribers/?limit=20&offset=0&with_follow=true"
+    ));
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(fixture())
+        .create_async()
+        .await;
+
+    let res = client
+        .get_blog_subscribers(blog, Some(20), Some(0), Some(true))
+        .await
+        .unwrap();
+
+    assert_eq!(res.total, 2);
+    let alice = &res.data[0];
+    assert_eq!(alice.level.as_ref().unwrap().name, "Pro");
+    assert_eq!(alice.status(), SubscriptionStatus::Active);
+    assert!(!alice.is_follower());
+
+    let bob = &res.data[1];
+    assert!(bob.level.is_none());
+    assert!(bob.is_follower());
+    assert_eq!(bob.status(), SubscriptionStatus::Suspended);
+}
+
+#[test]
+fn test_is_follower_uses_level_not_price() {
+    let mut value: serde_json::Value = serde_json::from_str(&fixture()).unwrap();
+    value["data"][0]["price"] = json!(0);
+    value["data"][1]["price"] = json!(300);
+    let response: SubscribersResponse = serde_json::from_value(value).unwrap();
+
+    assert!(!response.data[0].is_follower());
+    assert!(response.data[1].is_follower());
+}
+
+#[tokio::test]
+async fn test_get_blog_subscribers_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("GET", api_path("blog/blogx/subscribers/").as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client.get_blog_subscribers("blogx", None, None, None).await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_get_all_blog_subscribers_pages_until_total() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+
+    let first = server
+        .mock(
+            "GET",
+            api_path(&format!("blog/{blog}/subscribers/?limit=1")).as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_subscribers_page1.json").unwrap(),
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock(
+            "GET",
+            api_path(&format!("blog/{blog}/subscribers/?limit=1&offset=1")).as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            fs::read_to_string("tests/fixtures/api_response_subscribers_page2.json").unwrap(),
+        )
+        .create_async()
+        .await;
+
+    let all = client
+        .get_all_blog_subscribers(blog, Some(1), None)
+        .await
+        .unwrap();
+
+    let ids: Vec<u64> = all.iter().map(|s| s.id).collect();
+    assert_eq!(ids, vec![501, 502]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[test]
+fn test_subscribers_to_csv() {
+    let response: SubscribersResponse = serde_json::from_str(&fixture()).unwrap();
+
+    let csv = subscribers_to_csv(&response.data);
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines[0], SUBSCRIBER_CSV_HEADER.join(","));
+    assert_eq!(
+        lines[1],
+        "501,Alice,alice@example.com,2,Pro,5,1700000000,,1702592000,active"
+    );
+    assert_eq!(
+        lines[2],
+        "502,\"Bob, \"\"the reader\"\"\",,,,0,1705000000,,,suspended"
+    );
+}
+
+#[test]
+fn test_subscribers_to_json() {
+    let response: SubscribersResponse = serde_json::from_str(&fixture()).unwrap();
+
+    let exported = subscribers_to_json(&response.data).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&exported).unwrap();
+
+    assert_eq!(value[0]["level_name"], "Pro");
+    assert_eq!(value[0]["status"], "active");
+    assert_eq!(value[1]["level_id"], serde_json::Value::Null);
+    assert_eq!(value[1]["status"], "suspended");
+}
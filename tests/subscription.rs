@@ -1,6 +1,14 @@
 use std::fs;
 
-use boosty_api::{api_client::ApiClient, error::ApiError};
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{
+        CommentBlock, MediaData, NewSubscriptionLevel, Subscription, SubscriptionStatus,
+        SubscriptionsResponse,
+    },
+};
+use mockito::Matcher;
 use reqwest::{Client, header::CONTENT_TYPE};
 
 use crate::helpers::{api_path, setup};
@@ -117,4 +125,373 @@ async fn test_get_subscriptions_success() {
     let sub = &resp.data[0];
     assert_eq!(sub.id, 39989023);
     assert_eq!(resp.limit, 30);
+    assert!(matches!(
+        sub.subscription_level.data.as_slice(),
+        [MediaData::Text(_)]
+    ));
+}
+
+fn single_level_body() -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_subscription_levels.json").unwrap();
+    let value: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    value["data"][0].to_string()
+}
+
+#[tokio::test]
+async fn test_create_subscription_level_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let path = api_path(&format!("blog/{blog}/subscription_level/"));
+
+    let mock = server
+        .mock("POST", path.as_str())
+        .match_header(
+            "content-type",
+            Matcher::Regex("multipart/form-data; boundary=.*".into()),
+        )
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("name=\"name\"\r\n\r\nBasic".into()),
+            Matcher::Regex("name=\"price\"\r\n\r\n5".into()),
+            Matcher::Regex("name=\"is_limited\"\r\n\r\ntrue".into()),
+            Matcher::Regex("name=\"limit\"\r\n\r\n100".into()),
+            Matcher::Regex("name=\"data\\[\\]\"".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_level_body())
+        .create_async()
+        .await;
+
+    let level = NewSubscriptionLevel::new("Basic", 5.0)
+        .with_description(vec![
+            CommentBlock::text("Early access"),
+            CommentBlock::text_end(),
+        ])
+        .with_subscribers_limit(100);
+
+    let created = client
+        .create_subscription_level(blog, &level)
+        .await
+        .unwrap();
+    assert_eq!(created.id, 1);
+    assert_eq!(created.name, "Basic");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_subscription_level_invalid_input() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let cases = [
+        NewSubscriptionLevel::new("  ", 5.0),
+        NewSubscriptionLevel::new("Basic", -1.0),
+        NewSubscriptionLevel::new("Basic", f64::NAN),
+        NewSubscriptionLevel::new("Basic", 5.0).with_subscribers_limit(0),
+    ];
+
+    for level in cases {
+        let res = client.create_subscription_level("blogx", &level).await;
+        assert!(
+            matches!(res, Err(ApiError::InvalidInput(_))),
+            "Expected InvalidInput for {level:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_update_subscription_level_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let level_id = 1;
+    let path = api_path(&format!("blog/{blog}/subscription_level/{level_id}"));
+
+    let mock = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::Regex("name=\"is_limited\"\r\n\r\nfalse".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_level_body())
+        .create_async()
+        .await;
+
+    let level = NewSubscriptionLevel::new("Basic", 5.0);
+    let updated = client
+        .update_subscription_level(blog, level_id, &level)
+        .await
+        .unwrap();
+    assert_eq!(updated.id, level_id);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_archive_and_hide_subscription_level() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let level_id = 1;
+    let archive_path = api_path(&format!(
+        "blog/{blog}/subscription_level/{level_id}/archive/"
+    ));
+    let hide_path = api_path(&format!("blog/{blog}/subscription_level/{level_id}/hide/"));
+
+    let archive = server
+        .mock("PUT", archive_path.as_str())
+        .match_body(Matcher::UrlEncoded("is_archived".into(), "true".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_level_body())
+        .create_async()
+        .await;
+    let hide = server
+        .mock("PUT", hide_path.as_str())
+        .match_body(Matcher::UrlEncoded("is_hidden".into(), "false".into()))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(single_level_body())
+        .create_async()
+        .await;
+
+    client
+        .archive_subscription_level(blog, level_id, true)
+        .await
+        .unwrap();
+    client
+        .hide_subscription_level(blog, level_id, false)
+        .await
+        .unwrap();
+
+    archive.assert_async().await;
+    hide.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_delete_subscription_level() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let level_id = 1;
+    let path = api_path(&format!("blog/{blog}/subscription_level/{level_id}"));
+
+    let mock = server
+        .mock("DELETE", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client
+        .delete_subscription_level(blog, level_id)
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_delete_subscription_level_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let level_id = 1;
+    let path = api_path(&format!("blog/{blog}/subscription_level/{level_id}"));
+
+    server
+        .mock("DELETE", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client.delete_subscription_level(blog, level_id).await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+fn subscriptions_page(offset: u64, total: u64, id: u64) -> String {
+    let raw = fs::read_to_string("tests/fixtures/api_response_subscriptions.json").unwrap();
+    let mut value: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    value["data"][0]["id"] = serde_json::json!(id);
+    value["offset"] = serde_json::json!(offset);
+    value["total"] = serde_json::json!(total);
+    value["limit"] = serde_json::json!(1);
+    value.to_string()
+}
+
+fn fixture_subscription() -> Subscription {
+    let raw = fs::read_to_string("tests/fixtures/api_response_subscriptions.json").unwrap();
+    let mut response: SubscriptionsResponse = serde_json::from_str(&raw).unwrap();
+    response.data.remove(0)
+}
+
+#[tokio::test]
+async fn test_get_user_subscriptions_page_with_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let path = api_path("user/subscriptions?limit=1&offset=5");
+
+    let mock = server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(subscriptions_page(5, 6, 1))
+        .create_async()
+        .await;
+
+    let res = client
+        .get_user_subscriptions_page(Some(1), None, Some(5))
+        .await
+        .unwrap();
+    assert_eq!(res.offset, 5);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_all_user_subscriptions_pages_until_total() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let first = server
+        .mock("GET", api_path("user/subscriptions?limit=1").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(subscriptions_page(0, 2, 1))
+        .create_async()
+        .await;
+    let second = server
+        .mock(
+            "GET",
+            api_path("user/subscriptions?limit=1&offset=1").as_str(),
+        )
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(subscriptions_page(1, 2, 2))
+        .create_async()
+        .await;
+
+    let all = client
+        .get_all_user_subscriptions(Some(1), None)
+        .await
+        .unwrap();
+
+    let ids: Vec<u64> = all.iter().map(|s| s.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_all_user_subscriptions_ignores_stale_offset() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mut mocks = Vec::new();
+    for (query, id) in [
+        ("limit=1", 1),
+        ("limit=1&offset=1", 2),
+        ("limit=1&offset=2", 3),
+    ] {
+        let mock = server
+            .mock(
+                "GET",
+                api_path(&format!("user/subscriptions?{query}")).as_str(),
+            )
+            .with_status(200)
+            .with_header(CONTENT_TYPE, "application/json")
+            .with_body(subscriptions_page(0, 3, id))
+            .expect(1)
+            .create_async()
+            .await;
+        mocks.push(mock);
+    }
+
+    let all = client
+        .get_all_user_subscriptions(Some(1), None)
+        .await
+        .unwrap();
+
+    let ids: Vec<u64> = all.iter().map(|s| s.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_follow_and_unfollow_blog() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "jove";
+    let path = api_path(&format!("blog/{blog}/follow/"));
+
+    let follow = server
+        .mock("POST", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+    let unfollow = server
+        .mock("DELETE", path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client.follow_blog(blog).await.unwrap();
+    client.unfollow_blog(blog).await.unwrap();
+
+    follow.assert_async().await;
+    unfollow.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_follow_blog_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("POST", api_path("blog/jove/follow/").as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let res = client.follow_blog("jove").await;
+    assert!(matches!(res, Err(ApiError::Unauthorized)));
+}
+
+#[test]
+fn test_subscription_status_precedence() {
+    let mut sub = fixture_subscription();
+    assert_eq!(sub.status(), SubscriptionStatus::Active);
+    assert!(sub.is_active());
+
+    sub.is_pause = true;
+    assert_eq!(sub.status(), SubscriptionStatus::Paused);
+
+    sub.is_suspended = true;
+    assert_eq!(sub.status(), SubscriptionStatus::Suspended);
+
+    sub.is_archived = true;
+    assert_eq!(sub.status(), SubscriptionStatus::Archived);
+    assert!(!sub.is_active());
+}
+
+#[test]
+fn test_subscription_next_payment_within() {
+    let now = 1_700_000_000;
+    let mut sub = fixture_subscription();
+    assert!(!sub.next_payment_within(30, now));
+
+    sub.next_pay_time = Some(now + 3 * 86_400);
+    assert!(sub.next_payment_within(3, now));
+    assert!(!sub.next_payment_within(2, now));
+
+    sub.next_pay_time = Some(now - 1);
+    assert!(!sub.next_payment_within(30, now));
+
+    sub.next_pay_time = Some(i64::MAX);
+    assert!(sub.next_payment_within(u32::MAX, i64::MAX - 86_400));
 }
@@ -2,7 +2,10 @@ mod helpers;
 
 use std::fs;
 
-use boosty_api::{api_client::ApiClient, error::ApiError, model::TargetType};
+use boosty_api::{
+    api_client::ApiClient, error::ApiError, model::TargetType, target_watcher::TargetWatcher,
+};
+use mockito::Matcher;
 use reqwest::{Client, header::CONTENT_TYPE};
 use serde_json::json;
 
@@ -269,3 +272,131 @@ async fn test_update_target_http_error() {
     let res = client.update_blog_target(target_id, "desc", 100.0).await;
     assert!(res.is_err());
 }
+
+#[tokio::test]
+async fn test_delete_target_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let target_id = 456;
+    let path = api_path(format!("target/{}", target_id).as_str());
+
+    server
+        .mock("DELETE", path.as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let result = client.delete_blog_target(target_id).await;
+    assert!(matches!(result, Err(ApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_delete_target_http_error() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let target_id = 456;
+    let path = api_path(format!("target/{}", target_id).as_str());
+
+    server
+        .mock("DELETE", path.as_str())
+        .with_status(404)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(r#"{}"#)
+        .create_async()
+        .await;
+
+    let result = client.delete_blog_target(target_id).await;
+    assert!(matches!(result, Err(ApiError::HttpStatus { .. })));
+}
+
+#[tokio::test]
+async fn test_reorder_targets_success() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let path = api_path(&format!("target/{blog}/order/"));
+
+    let mock = server
+        .mock("PUT", path.as_str())
+        .match_body(Matcher::Json(json!({"target_ids": [600102, 600101]})))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    client
+        .reorder_blog_targets(blog, &[600102, 600101])
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_reorder_targets_invalid_input() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let empty = client.reorder_blog_targets("blogx", &[]).await;
+    assert!(matches!(empty, Err(ApiError::InvalidInput(_))));
+
+    let duplicate = client.reorder_blog_targets("blogx", &[1, 1]).await;
+    assert!(matches!(duplicate, Err(ApiError::InvalidInput(_))));
+}
+
+#[test]
+fn test_target_progress_per_type() {
+    let raw = fs::read_to_string("tests/fixtures/api_response_targets.json").unwrap();
+    let targets: boosty_api::model::TargetResponse = serde_json::from_str(&raw).unwrap();
+
+    let money = targets.data[1].progress();
+    assert_eq!(targets.data[1].type_, TargetType::Money);
+    assert!((money.percent - 18750.56 / 900000.0 * 100.0).abs() < 1e-9);
+    assert!((money.remaining - 881249.44).abs() < 1e-6);
+    assert!(!money.is_completed);
+
+    let subscribers = targets.data[3].progress();
+    assert_eq!(targets.data[3].type_, TargetType::Subscribers);
+    assert_eq!(subscribers.remaining, 199440.0);
+}
+
+#[tokio::test]
+async fn test_target_watcher_reports_completed_between_polls() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let blog = "blogx";
+    let path = api_path(&format!("target/{blog}/"));
+
+    let raw = fs::read_to_string("tests/fixtures/api_response_targets.json").unwrap();
+    let mut completed: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    completed["data"][1]["currentSum"] = json!(900000.0);
+
+    let before = server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(raw)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut watcher = TargetWatcher::new(blog);
+    assert!(watcher.poll(&client).await.unwrap().is_empty());
+    before.assert_async().await;
+    before.remove_async().await;
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(completed.to_string())
+        .create_async()
+        .await;
+
+    let done = watcher.poll(&client).await.unwrap();
+    assert_eq!(done.len(), 1);
+    assert_eq!(done[0].id, 600102);
+    assert_eq!(done[0].progress().percent, 100.0);
+}
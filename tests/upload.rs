@@ -0,0 +1,204 @@
+mod helpers;
+
+use std::sync::{Arc, Mutex};
+
+use boosty_api::{
+    api_client::ApiClient,
+    error::ApiError,
+    model::{CommentBlock, MediaUpload},
+};
+use mockito::Matcher;
+use reqwest::{Client, header::CONTENT_TYPE};
+use serde_json::json;
+
+use crate::helpers::{api_path, setup};
+
+#[tokio::test]
+async fn test_upload_image_from_bytes_reports_progress() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("media/image/").as_str())
+        .match_header(
+            "content-type",
+            Matcher::Regex("^multipart/form-data".into()),
+        )
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"name="file"; filename="cover.png""#.into()),
+            Matcher::Regex("Content-Type: image/png".into()),
+            Matcher::Regex("PNGDATA".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({ "id": "img-1", "url": "https://images.boosty.to/img-1", "width": 10, "height": 20 })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let sink = reports.clone();
+    let upload = MediaUpload::from_bytes("cover.png", b"PNGDATA".to_vec())
+        .with_progress(move |sent, total| sink.lock().unwrap().push((sent, total)));
+
+    let image = client.upload_image(upload).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(image.id, "img-1");
+    assert_eq!(image.width, Some(10));
+    assert_eq!(reports.lock().unwrap().last(), Some(&(7, 7)));
+
+    let block = serde_json::to_value(CommentBlock::from(&image)).unwrap();
+    assert_eq!(block, json!({ "type": "image", "id": "img-1" }));
+}
+
+#[tokio::test]
+async fn test_upload_audio_from_path() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let file_path = std::env::temp_dir().join("boosty_api_upload_test.mp3");
+    tokio::fs::write(&file_path, b"ID3AUDIO").await.unwrap();
+
+    let mock = server
+        .mock("POST", api_path("media/audio/").as_str())
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"filename="boosty_api_upload_test.mp3""#.into()),
+            Matcher::Regex("Content-Type: audio/mpeg".into()),
+            Matcher::Regex("ID3AUDIO".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({ "id": "aud-1", "url": "https://boosty.to/aud-1", "title": "Track", "size": 8 })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let audio = client
+        .upload_audio(MediaUpload::from_path(&file_path))
+        .await
+        .unwrap();
+    tokio::fs::remove_file(&file_path).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(audio.size, 8);
+    assert_eq!(
+        serde_json::to_value(CommentBlock::from(&audio)).unwrap(),
+        json!({ "type": "audio_file", "id": "aud-1" })
+    );
+}
+
+#[tokio::test]
+async fn test_upload_file_from_reader() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("media/file/").as_str())
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"filename="notes.bin""#.into()),
+            Matcher::Regex("Content-Type: application/octet-stream".into()),
+            Matcher::Regex("streamed-content".into()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({ "id": "file-1", "url": "https://boosty.to/file-1", "title": "notes.bin", "size": 16 })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let content = b"streamed-content".to_vec();
+    let length = content.len() as u64;
+    let upload = MediaUpload::from_reader("notes.bin", std::io::Cursor::new(content), length);
+
+    let file = client.upload_file(upload).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(file.title, "notes.bin");
+}
+
+#[tokio::test]
+async fn test_upload_from_reader_length_mismatch() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("POST", api_path("media/file/").as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body(
+            json!({ "id": "file-1", "url": "", "title": "notes.bin", "size": 16 }).to_string(),
+        )
+        .create_async()
+        .await;
+
+    let content = b"streamed-content".to_vec();
+    for length in [content.len() as u64 + 4, content.len() as u64 - 4] {
+        let upload =
+            MediaUpload::from_reader("notes.bin", std::io::Cursor::new(content.clone()), length);
+        let err = client.upload_file(upload).await.unwrap_err();
+        assert!(matches!(err, ApiError::InvalidInput(_)), "{err:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_upload_image_rejects_wrong_mime_before_request() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let mock = server
+        .mock("POST", api_path("media/image/").as_str())
+        .expect(0)
+        .create_async()
+        .await;
+
+    let err = client
+        .upload_image(MediaUpload::from_bytes("song.mp3", vec![1, 2, 3]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+
+    let err = client
+        .upload_image(MediaUpload::from_bytes("empty.png", Vec::new()))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_upload_from_missing_path() {
+    let (_server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    let err = client
+        .upload_file(MediaUpload::from_path("/nonexistent/boosty_api/file.bin"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::Io(_)));
+}
+
+#[tokio::test]
+async fn test_upload_unauthorized() {
+    let (mut server, base) = setup().await;
+    let client = ApiClient::new(Client::new(), &base);
+
+    server
+        .mock("POST", api_path("media/file/").as_str())
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let err = client
+        .upload_file(MediaUpload::from_bytes("a.txt", b"x".to_vec()))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::Unauthorized));
+}